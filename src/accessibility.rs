@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+
+/// A node in the accessibility tree of the current page, as returned by
+/// [WebDriver::accessibility_tree()](struct.WebDriver.html#method.accessibility_tree).
+///
+/// The `Display` implementation pretty-prints the tree, one node per line, indented by depth.
+/// Ignored nodes are omitted from the output, but their children are still shown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    /// The computed role of this node, e.g. `"button"` or `"heading"`.
+    pub role: String,
+    /// The computed accessible name of this node. This will be empty if the node has no name.
+    pub name: String,
+    /// True if this node is ignored for accessibility purposes, otherwise false.
+    pub ignored: bool,
+    /// The child nodes of this node.
+    pub children: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityNode {
+    /// Build the tree from the flat list of nodes returned by the Chrome DevTools Protocol
+    /// command `Accessibility.getFullAXTree`. Returns `None` if there is no root node.
+    pub(crate) fn from_cdp_nodes(nodes: &[Value]) -> Option<Self> {
+        let mut nodes_by_id: HashMap<&str, &Value> =
+            nodes.iter().filter_map(|node| node["nodeId"].as_str().map(|id| (id, node))).collect();
+        let root = nodes.iter().find(|node| node["parentId"].is_null())?;
+        Some(Self::build(root, &mut nodes_by_id))
+    }
+
+    fn build<'a>(node: &'a Value, nodes_by_id: &mut HashMap<&'a str, &'a Value>) -> Self {
+        // Removing each node as it is visited guards against cycles in malformed trees.
+        if let Some(id) = node["nodeId"].as_str() {
+            nodes_by_id.remove(id);
+        }
+
+        let mut children = Vec::new();
+        if let Some(child_ids) = node["childIds"].as_array() {
+            for child_id in child_ids.iter().filter_map(|id| id.as_str()) {
+                if let Some(child) = nodes_by_id.remove(child_id) {
+                    children.push(Self::build(child, nodes_by_id));
+                }
+            }
+        }
+
+        AccessibilityNode {
+            role: node["role"]["value"].as_str().unwrap_or_default().to_string(),
+            name: node["name"]["value"].as_str().unwrap_or_default().to_string(),
+            ignored: node["ignored"].as_bool().unwrap_or(false),
+            children,
+        }
+    }
+
+    /// Search this node and all of its descendants (depth-first) for the first node with the
+    /// specified role and accessible name.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let tree = driver.accessibility_tree()?;
+    /// let button = tree.find("button", "Submit").expect("Submit button not found");
+    /// ```
+    pub fn find(&self, role: &str, name: &str) -> Option<&AccessibilityNode> {
+        if self.role == role && self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(role, name))
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let mut child_depth = depth;
+        if !self.ignored {
+            if self.name.is_empty() {
+                writeln!(f, "{:indent$}{}", "", self.role, indent = depth * 2)?;
+            } else {
+                writeln!(f, "{:indent$}{} \"{}\"", "", self.role, self.name, indent = depth * 2)?;
+            }
+            child_depth += 1;
+        }
+
+        for child in &self.children {
+            child.fmt_indented(f, child_depth)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AccessibilityNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(id: &str, parent: Option<&str>, children: &[&str], role: &str, name: &str) -> Value {
+        json!({
+            "nodeId": id,
+            "parentId": parent,
+            "childIds": children,
+            "role": { "type": "role", "value": role },
+            "name": { "type": "computedString", "value": name },
+            "ignored": false,
+        })
+    }
+
+    #[test]
+    fn from_cdp_nodes_builds_tree_in_child_order() {
+        // Nodes are deliberately out of order, as the protocol does not guarantee the order.
+        let nodes = vec![
+            node("3", Some("1"), &[], "button", "Submit"),
+            node("1", None, &["2", "3"], "RootWebArea", "Demo"),
+            node("2", Some("1"), &[], "heading", "Title"),
+        ];
+        let tree = AccessibilityNode::from_cdp_nodes(&nodes).unwrap();
+        assert_eq!(tree.role, "RootWebArea");
+        assert_eq!(tree.name, "Demo");
+        let roles: Vec<&str> = tree.children.iter().map(|c| c.role.as_str()).collect();
+        assert_eq!(roles, vec!["heading", "button"]);
+        assert_eq!(tree.find("button", "Submit").map(|n| n.name.as_str()), Some("Submit"));
+        assert!(tree.find("button", "Cancel").is_none());
+    }
+
+    #[test]
+    fn from_cdp_nodes_without_root() {
+        assert!(AccessibilityNode::from_cdp_nodes(&[]).is_none());
+        let nodes = vec![node("2", Some("1"), &[], "button", "Orphan")];
+        assert!(AccessibilityNode::from_cdp_nodes(&nodes).is_none());
+    }
+
+    #[test]
+    fn from_cdp_nodes_ignores_cycles_and_missing_children() {
+        let nodes = vec![
+            node("1", None, &["2", "missing"], "RootWebArea", ""),
+            node("2", Some("1"), &["1", "2"], "generic", ""),
+        ];
+        let tree = AccessibilityNode::from_cdp_nodes(&nodes).unwrap();
+        assert_eq!(tree.children.len(), 1);
+        assert!(tree.children[0].children.is_empty());
+    }
+
+    #[test]
+    fn display_skips_ignored_nodes() {
+        let mut ignored = node("2", Some("1"), &["3"], "generic", "");
+        ignored["ignored"] = json!(true);
+        let nodes = vec![
+            node("1", None, &["2"], "RootWebArea", "Demo"),
+            ignored,
+            node("3", Some("2"), &[], "button", "Submit"),
+        ];
+        let tree = AccessibilityNode::from_cdp_nodes(&nodes).unwrap();
+        assert_eq!(tree.to_string(), "RootWebArea \"Demo\"\n  button \"Submit\"\n");
+    }
+}
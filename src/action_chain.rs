@@ -1,3 +1,6 @@
+use serde::Serialize;
+use serde_json::json;
+
 use crate::webdrivercommands::WebDriverCommands;
 use crate::WebDriverSession;
 use crate::{
@@ -10,6 +13,25 @@ use crate::{
     WebElement,
 };
 
+/// A single action for a touch pointer (finger), for use with
+/// [ActionChain::touch_tick()](struct.ActionChain.html#method.touch_tick).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchAction {
+    /// Move the finger to the specified X and Y coordinates.
+    MoveTo(i32, i32),
+    /// Move the finger by the specified X and Y offsets.
+    MoveBy(i32, i32),
+    /// Touch the screen.
+    Down,
+    /// Lift the finger from the screen.
+    Up,
+}
+
+/// Get the number of actions in the specified action source.
+fn action_count<T: Serialize>(source: &T) -> usize {
+    json!(source)["actions"].as_array().map(|actions| actions.len()).unwrap_or(0)
+}
+
 /// The ActionChain struct allows you to perform multiple input actions in
 /// a sequence, including drag-and-drop, send keystrokes to an element, and
 /// hover the mouse over an element.
@@ -25,6 +47,7 @@ pub struct ActionChain<'a> {
     session: &'a WebDriverSession,
     key_actions: ActionSource<KeyAction>,
     pointer_actions: ActionSource<PointerAction>,
+    touch_actions: Vec<ActionSource<PointerAction>>,
 }
 
 impl<'a> ActionChain<'a> {
@@ -40,6 +63,7 @@ impl<'a> ActionChain<'a> {
                 "pointer",
                 PointerActionType::Mouse,
             ),
+            touch_actions: Vec::new(),
         }
     }
 
@@ -79,7 +103,9 @@ impl<'a> ActionChain<'a> {
     /// Perform the action sequence. No actions are actually performed until
     /// this method is called.
     pub fn perform(&self) -> WebDriverResult<()> {
-        let actions = Actions::from(serde_json::json!([self.key_actions, self.pointer_actions]));
+        let mut sources = vec![json!(self.key_actions), json!(self.pointer_actions)];
+        sources.extend(self.touch_actions.iter().map(|source| json!(source)));
+        let actions = Actions::from(json!(sources));
         self.cmd(Command::PerformActions(actions))?;
         Ok(())
     }
@@ -698,4 +724,97 @@ impl<'a> ActionChain<'a> {
     {
         self.click_element(element).send_keys(text)
     }
+
+    /// Get the touch action source for the specified finger, creating it if necessary, and
+    /// pad it with pauses so that its next action happens in the current tick.
+    fn touch_source(&mut self, finger: u32) -> &mut ActionSource<PointerAction> {
+        let finger = finger as usize;
+        while self.touch_actions.len() <= finger {
+            let id = format!("touch{}", self.touch_actions.len());
+            self.touch_actions
+                .push(ActionSource::<PointerAction>::new(&id, PointerActionType::Touch));
+        }
+
+        // The key actions contain exactly one action per tick.
+        let ticks = action_count(&self.key_actions);
+        let source = &mut self.touch_actions[finger];
+        for _ in action_count(&*source)..ticks {
+            source.pause();
+        }
+        source
+    }
+
+    /// Perform the specified touch actions simultaneously, i.e. in a single tick.
+    /// Each action is performed by the finger with the specified index (starting at 0),
+    /// and each finger should appear at most once per tick.
+    ///
+    /// This allows multi-touch gestures such as pinch and zoom, where two fingers must move
+    /// at the same time. Each finger is a separate touch pointer source. The other
+    /// single-finger touch methods such as `touch_down()` are built on this method.
+    ///
+    /// **NOTE:** Touch actions require a browser that supports touch input, such as a
+    /// mobile browser or a desktop browser with touch emulation enabled.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// use thirtyfour_sync::action_chain::TouchAction;
+    ///
+    /// // Spread two fingers apart horizontally.
+    /// driver.action_chain()
+    ///     .touch_tick(&[(0, TouchAction::MoveTo(200, 300)), (1, TouchAction::MoveTo(220, 300))])
+    ///     .touch_tick(&[(0, TouchAction::Down), (1, TouchAction::Down)])
+    ///     .touch_tick(&[(0, TouchAction::MoveBy(-50, 0)), (1, TouchAction::MoveBy(50, 0))])
+    ///     .touch_tick(&[(0, TouchAction::Up), (1, TouchAction::Up)])
+    ///     .perform()?;
+    /// ```
+    pub fn touch_tick(mut self, actions: &[(u32, TouchAction)]) -> Self {
+        for (finger, action) in actions {
+            let source = self.touch_source(*finger);
+            match action {
+                TouchAction::MoveTo(x, y) => source.move_to(*x, *y),
+                TouchAction::MoveBy(x, y) => source.move_by(*x, *y),
+                TouchAction::Down => source.click_and_hold(),
+                TouchAction::Up => source.release(),
+            }
+        }
+        self.key_actions.pause();
+        self.pointer_actions.pause();
+        self
+    }
+
+    /// Move the specified finger to the specified X and Y coordinates and touch the screen.
+    ///
+    /// See `touch_tick()` for performing actions with multiple fingers at the same time.
+    pub fn touch_down(self, finger: u32, x: i32, y: i32) -> Self {
+        self.touch_tick(&[(finger, TouchAction::MoveTo(x, y))])
+            .touch_tick(&[(finger, TouchAction::Down)])
+    }
+
+    /// Move the specified finger to the specified X and Y coordinates. If the finger is
+    /// touching the screen, this performs a swipe.
+    pub fn touch_move(self, finger: u32, x: i32, y: i32) -> Self {
+        self.touch_tick(&[(finger, TouchAction::MoveTo(x, y))])
+    }
+
+    /// Lift the specified finger from the screen.
+    pub fn touch_up(self, finger: u32) -> Self {
+        self.touch_tick(&[(finger, TouchAction::Up)])
+    }
+
+    /// Tap the center of the specified element with a single finger.
+    ///
+    /// **NOTE:** This requires a browser that supports touch input, such as a mobile browser
+    /// or a desktop browser with touch emulation enabled.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// driver.action_chain().tap(&elem).perform()?;
+    /// ```
+    pub fn tap(mut self, element: &WebElement) -> Self {
+        self.touch_source(0).move_to_element_center(element.element_id.clone());
+        self.key_actions.pause();
+        self.pointer_actions.pause();
+        self.touch_tick(&[(0, TouchAction::Down)]).touch_tick(&[(0, TouchAction::Up)])
+    }
 }
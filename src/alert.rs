@@ -146,3 +146,30 @@ impl<'a> Alert<'a> {
         self.cmd(Command::SendAlertText(keys.into())).map(|_| ())
     }
 }
+
+/// Javascript that replaces `window.alert()`, `window.confirm()` and `window.prompt()` with
+/// functions that dismiss the dialog immediately. The original functions are kept so that
+/// they can be restored by `RESTORE_DIALOGS_SCRIPT`.
+pub(crate) const DISMISS_DIALOGS_SCRIPT: &str = r#"
+if (!window.__thirtyfourDialogs) {
+    window.__thirtyfourDialogs = {
+        alert: window.alert,
+        confirm: window.confirm,
+        prompt: window.prompt
+    };
+    window.alert = function() {};
+    window.confirm = function() { return false; };
+    window.prompt = function() { return null; };
+}
+"#;
+
+/// Javascript that restores the dialog functions replaced by `DISMISS_DIALOGS_SCRIPT`.
+pub(crate) const RESTORE_DIALOGS_SCRIPT: &str = r#"
+let dialogs = window.__thirtyfourDialogs;
+if (dialogs) {
+    window.alert = dialogs.alert;
+    window.confirm = dialogs.confirm;
+    window.prompt = dialogs.prompt;
+    delete window.__thirtyfourDialogs;
+}
+"#;
@@ -0,0 +1,169 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::error::WebDriverResult;
+use crate::{Capabilities, ChromeCapabilities};
+
+/// Counter to keep temporary profile directories unique within this process.
+static TEMP_PROFILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Helper methods for setting arbitrary capabilities, such as vendor-specific options.
+///
+/// This is implemented for `DesiredCapabilities` as well as all of the browser-specific
+/// capabilities types, so you can add provider-specific blocks (e.g. `sauce:options` or
+/// `bstack:options`) without needing a typed struct for each vendor. Capabilities are sent
+/// to the WebDriver server under `alwaysMatch` when creating the session.
+///
+/// # Example:
+/// ```rust
+/// use thirtyfour_sync::prelude::*;
+/// use serde_json::json;
+///
+/// let mut caps = DesiredCapabilities::chrome();
+/// caps.set_capability("sauce:options", json!({ "name": "My test", "build": "1.0" }));
+/// caps.set_capability("se:recordVideo", json!(true));
+/// assert_eq!(caps.capability("se:recordVideo"), Some(&json!(true)));
+/// ```
+pub trait CapabilitiesExt {
+    /// Set the specified capability, replacing any existing value.
+    fn set_capability(&mut self, key: &str, value: Value);
+
+    /// Get the value of the specified capability, if set.
+    fn capability(&self, key: &str) -> Option<&Value>;
+
+    /// Remove the specified capability, returning the previous value if it was set.
+    fn remove_capability(&mut self, key: &str) -> Option<Value>;
+}
+
+impl<T: Capabilities> CapabilitiesExt for T {
+    fn set_capability(&mut self, key: &str, value: Value) {
+        self.get_mut()[key] = value;
+    }
+
+    fn capability(&self, key: &str) -> Option<&Value> {
+        self.get().get(key)
+    }
+
+    fn remove_capability(&mut self, key: &str) -> Option<Value> {
+        self.get_mut().as_object_mut().and_then(|caps| caps.remove(key))
+    }
+}
+
+/// Helper methods for Chrome-specific capabilities.
+pub trait ChromeCapabilitiesExt {
+    /// Start Chrome with a new, empty profile in a uniquely named temporary directory, by
+    /// setting the `--user-data-dir` argument. Returns the path of the profile directory.
+    ///
+    /// By default, chromedriver also creates a temporary profile for each session. However,
+    /// if other arguments or tools make sessions share a profile, parallel sessions can
+    /// collide on it (e.g. "user data directory is already in use"). This guarantees each
+    /// session its own profile, cookies and cache.
+    ///
+    /// Chrome creates the directory when it starts, but does not delete it afterwards, so
+    /// you should remove it once the session has ended. The path is generated on this
+    /// machine, so if the browser runs on a remote machine, the directory is created there.
+    ///
+    /// Firefox does not need an equivalent, since geckodriver always starts each session
+    /// with a fresh temporary profile, and deletes it when the session ends.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// use thirtyfour_sync::ChromeCapabilitiesExt;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// let profile_dir = caps.use_temp_profile()?;
+    /// let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.quit()?;
+    /// std::fs::remove_dir_all(&profile_dir).ok();
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn use_temp_profile(&mut self) -> WebDriverResult<PathBuf>;
+}
+
+impl ChromeCapabilitiesExt for ChromeCapabilities {
+    fn use_temp_profile(&mut self) -> WebDriverResult<PathBuf> {
+        let timestamp =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+        let counter = TEMP_PROFILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "thirtyfour-profile-{}-{}-{}",
+            std::process::id(),
+            timestamp,
+            counter
+        ));
+        self.add_chrome_arg(&format!("--user-data-dir={}", path.display()))?;
+        Ok(path)
+    }
+}
+
+/// Capabilities for W3C session negotiation with multiple fallback capability sets.
+///
+/// The WebDriver server will use the first `firstMatch` entry it can satisfy, merged with the
+/// `alwaysMatch` capabilities. This allows you to request e.g. "Chrome or Firefox" from a
+/// Selenium Grid. The capabilities actually chosen are available via
+/// `WebDriver::capabilities()` once the session has started.
+///
+/// **NOTE:** The W3C spec requires that no capability is set in both `alwaysMatch` and
+/// a `firstMatch` entry, otherwise the server will reject the request.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use thirtyfour_sync::{Capabilities, FirstMatchCapabilities};
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let caps = FirstMatchCapabilities::new()
+///     .first_match(DesiredCapabilities::chrome())
+///     .first_match(DesiredCapabilities::firefox());
+/// let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// let browser_name = driver.capabilities().get()["browserName"].clone();
+/// #     assert!(browser_name == "chrome" || browser_name == "firefox");
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct FirstMatchCapabilities {
+    #[serde(rename = "alwaysMatch")]
+    always_match: Value,
+    #[serde(rename = "firstMatch")]
+    first_match: Vec<Value>,
+}
+
+impl Default for FirstMatchCapabilities {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FirstMatchCapabilities {
+    /// Create a new FirstMatchCapabilities with empty `alwaysMatch` capabilities and no
+    /// `firstMatch` entries.
+    pub fn new() -> Self {
+        FirstMatchCapabilities {
+            always_match: json!({}),
+            first_match: Vec::new(),
+        }
+    }
+
+    /// Set the capabilities that must always match, regardless of which `firstMatch` entry
+    /// is chosen.
+    pub fn always_match<C: Capabilities>(mut self, capabilities: C) -> Self {
+        self.always_match = capabilities.get().clone();
+        self
+    }
+
+    /// Add a `firstMatch` entry. Entries are tried in the order they are added.
+    pub fn first_match<C: Capabilities>(mut self, capabilities: C) -> Self {
+        self.first_match.push(capabilities.get().clone());
+        self
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::{no_such_element, WebDriverError, WebDriverErrorInfo, WebDriverResult};
+use crate::{ScriptArgs, WebDriverCommands, WebElement};
+
+/// Convenience wrapper for `<input>` elements with suggestions provided by a `<datalist>`.
+///
+/// The datalist is resolved via the `list` attribute of the input.
+///
+/// # Example:
+/// ```ignore
+/// use thirtyfour_sync::components::datalist::DatalistInput;
+///
+/// let elem = driver.find_element(By::Id("browser"))?;
+/// let input = DatalistInput::new(&elem)?;
+/// assert!(input.suggestions()?.contains(&"Firefox".to_string()));
+/// input.choose("Firefox")?;
+/// ```
+pub struct DatalistInput<'a> {
+    element: WebElement<'a>,
+}
+
+impl<'a> DatalistInput<'a> {
+    /// Instantiate a new DatalistInput struct. The specified element must be an `<input>`
+    /// element with a `list` attribute.
+    pub fn new(element: &WebElement<'a>) -> WebDriverResult<DatalistInput<'a>> {
+        if element.get_attribute("list")?.is_none() {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Element does not have a list attribute",
+            )));
+        }
+        let element = element.clone();
+        Ok(DatalistInput {
+            element,
+        })
+    }
+
+    /// Return the values of all options in the linked `<datalist>`, in document order.
+    pub fn suggestions(&self) -> WebDriverResult<Vec<String>> {
+        let mut args = ScriptArgs::new();
+        args.push(&self.element)?;
+        let ret = self.element.session.execute_script_with_args(
+            r#"
+            let list = arguments[0].list;
+            return list ? Array.from(list.options).map(o => o.value) : null;
+            "#,
+            &args,
+        )?;
+        if ret.value().is_null() {
+            return Err(no_such_element("Could not locate the datalist for this input"));
+        }
+        ret.convert()
+    }
+
+    /// Set the value of the input to the specified value, and dispatch the `input` and
+    /// `change` events as if the user had chosen a suggestion.
+    ///
+    /// The value does not need to be one of the suggestions, since the browser also allows
+    /// users to type any value.
+    pub fn choose(&self, value: &str) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self.element)?;
+        args.push(value)?;
+        self.element.session.execute_script_with_args(
+            r#"
+            let input = arguments[0];
+            // Use the native setter so that frameworks tracking the value see the change.
+            let setter = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, "value").set;
+            setter.call(input, arguments[1]);
+            input.dispatchEvent(new Event("input", { bubbles: true }));
+            input.dispatchEvent(new Event("change", { bubbles: true }));
+            "#,
+            &args,
+        )?;
+        Ok(())
+    }
+}
@@ -19,8 +19,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::error::{no_such_element, WebDriverError, WebDriverResult};
-use crate::{By, WebElement};
+use crate::error::{no_such_element, WebDriverError, WebDriverErrorInfo, WebDriverResult};
+use crate::{By, ScriptArgs, WebDriverCommands, WebElement};
 
 /// Set the selection state of the specified element.
 fn set_selected(element: &WebElement<'_>, select: bool) -> WebDriverResult<()> {
@@ -82,11 +82,58 @@ impl<'a> SelectElement<'a> {
         })
     }
 
+    /// Find the `<select>` element matching the specified selector and wrap it in a
+    /// SelectElement.
+    ///
+    /// Returns `WebDriverError::InvalidArgument` if the matched element is not a `<select>`.
+    pub fn from_driver<D>(driver: &'a D, by: By) -> WebDriverResult<SelectElement<'a>>
+    where
+        D: WebDriverCommands,
+    {
+        let element = driver.find_element(by)?;
+        Self::from_select_element(&element)
+    }
+
+    /// Find the `<select>` element matching the specified selector, relative to the
+    /// specified parent element, and wrap it in a SelectElement.
+    ///
+    /// Returns `WebDriverError::InvalidArgument` if the matched element is not a `<select>`.
+    pub fn from_element(parent: &'a WebElement, by: By) -> WebDriverResult<SelectElement<'a>> {
+        let element = parent.find_element(by)?;
+        Self::from_select_element(&element)
+    }
+
+    /// Check that the specified element is a `<select>` element before wrapping it.
+    fn from_select_element(element: &WebElement<'a>) -> WebDriverResult<SelectElement<'a>> {
+        let tag_name = element.tag_name()?.to_lowercase();
+        if tag_name != "select" {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                "Element is a <{}>, not a <select>",
+                tag_name
+            ))));
+        }
+        Self::new(element)
+    }
+
     /// Return a vec of all options belonging to this select tag.
     pub fn options(&self) -> WebDriverResult<Vec<WebElement>> {
         self.element.find_elements(By::Tag("option"))
     }
 
+    /// Return the `(value, visible_text)` of all options belonging to this select tag.
+    ///
+    /// This fetches all options in a single request, which is much faster than calling
+    /// `value()` and `text()` on each option returned by `options()`.
+    pub fn options_with_text(&self) -> WebDriverResult<Vec<(String, String)>> {
+        let mut args = ScriptArgs::new();
+        args.push(&self.element)?;
+        let ret = self.element.session.execute_script_with_args(
+            r#"return Array.from(arguments[0].options).map(o => [o.value, o.text.trim()]);"#,
+            &args,
+        )?;
+        ret.convert()
+    }
+
     /// Return a vec of all selected options belonging to this select tag.
     pub fn all_selected_options(&self) -> WebDriverResult<Vec<WebElement>> {
         let mut selected = Vec::new();
@@ -108,6 +155,26 @@ impl<'a> SelectElement<'a> {
         Err(no_such_element("No options are selected"))
     }
 
+    /// Return the index of the first selected option in this select tag, or `None` if no
+    /// options are selected.
+    ///
+    /// This is the same as the "index" attribute of the option, which is used by
+    /// `select_by_index()`.
+    pub fn first_selected_index(&self) -> WebDriverResult<Option<u32>> {
+        let mut args = ScriptArgs::new();
+        args.push(&self.element)?;
+        let ret = self
+            .element
+            .session
+            .execute_script_with_args(r#"return arguments[0].selectedIndex;"#, &args)?;
+        let index: i64 = ret.convert()?;
+        Ok(if index < 0 {
+            None
+        } else {
+            Some(index as u32)
+        })
+    }
+
     /// Set selection state for all options.
     fn set_selection_all(&self, select: bool) -> WebDriverResult<()> {
         for option in self.options()? {
@@ -252,6 +319,42 @@ impl<'a> SelectElement<'a> {
         self.set_selection_by_visible_text(text, true)
     }
 
+    /// Select the option with visible text matching the specified text, by focusing the
+    /// select tag and typing the text, as a user would.
+    ///
+    /// This relies on the browser's type-ahead selection, and is useful for native dropdowns
+    /// where the option elements cannot be clicked, or where the page only reacts to the
+    /// events fired by keyboard input. Leading and trailing whitespace is ignored when
+    /// comparing the text.
+    ///
+    /// Returns `WebDriverError::NoSuchElement` if no option has the specified text, or
+    /// `WebDriverError::InvalidElementState` if typing the text selected a different option.
+    pub fn select_by_visible_text_with_keys(&self, text: &str) -> WebDriverResult<()> {
+        let text = text.trim();
+        let options = self.options_with_text()?;
+        if !options.iter().any(|(_, option_text)| option_text == text) {
+            return Err(no_such_element(&format!(
+                "Could not locate element with visible text: {}",
+                text
+            )));
+        }
+
+        self.element.focus()?;
+        self.element.send_keys(text)?;
+
+        let selected = self
+            .first_selected_index()?
+            .and_then(|index| options.get(index as usize))
+            .map(|(_, option_text)| option_text.as_str());
+        if selected != Some(text) {
+            return Err(WebDriverError::InvalidElementState(WebDriverErrorInfo::new(&format!(
+                "Typing {:?} selected {:?} instead of the matching option",
+                text, selected
+            ))));
+        }
+        Ok(())
+    }
+
     /// Select options matching the specified XPath condition.
     /// E.g. The specified condition replaces `{}` in this XPath: `.//option[{}]`
     ///
@@ -0,0 +1,95 @@
+use serde::Deserialize;
+
+use crate::error::{no_such_element, WebDriverError, WebDriverErrorInfo, WebDriverResult};
+use crate::{ScriptArgs, WebDriverCommands, WebElement};
+
+/// The text of a table, as returned by `TABLE_SCRIPT`.
+#[derive(Debug, Deserialize)]
+struct TableContents {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+const TABLE_SCRIPT: &str = r#"
+let table = arguments[0];
+let text = cell => cell.innerText.trim();
+let headerRow = table.tHead && table.tHead.rows.length > 0 ? table.tHead.rows[0] : null;
+if (!headerRow && table.rows.length > 0) {
+    let cells = Array.from(table.rows[0].cells);
+    if (cells.length > 0 && cells.every(c => c.tagName === "TH")) {
+        headerRow = table.rows[0];
+    }
+}
+return {
+    headers: headerRow ? Array.from(headerRow.cells).map(text) : [],
+    rows: Array.from(table.rows)
+        .filter(r => r !== headerRow && r.parentElement !== table.tHead)
+        .map(r => Array.from(r.cells).map(text))
+};
+"#;
+
+/// Convenience wrapper for `<table>` elements.
+///
+/// Each method reads the text of the whole table in a single script call, which is much
+/// faster than finding the rows and cells individually.
+///
+/// The headers are taken from the first row of the `<thead>` if there is one, otherwise
+/// from the first row of the table if it contains only `<th>` cells. All other rows are
+/// returned by `rows()`, including any `<tfoot>` rows. Cells spanning multiple columns
+/// are returned once, so rows may have different lengths.
+///
+/// # Example:
+/// ```ignore
+/// use thirtyfour_sync::components::table::TableElement;
+///
+/// let elem = driver.find_element(By::Id("results"))?;
+/// let table = TableElement::new(&elem)?;
+/// assert_eq!(table.headers()?, vec!["Name", "Score"]);
+/// assert_eq!(table.cell(0, 1)?, "42");
+/// ```
+pub struct TableElement<'a> {
+    element: WebElement<'a>,
+}
+
+impl<'a> TableElement<'a> {
+    /// Instantiate a new TableElement struct. The specified element must be a `<table>`.
+    pub fn new(element: &WebElement<'a>) -> WebDriverResult<TableElement<'a>> {
+        if !element.tag_name()?.eq_ignore_ascii_case("table") {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Element is not a table",
+            )));
+        }
+        let element = element.clone();
+        Ok(TableElement {
+            element,
+        })
+    }
+
+    fn contents(&self) -> WebDriverResult<TableContents> {
+        let mut args = ScriptArgs::new();
+        args.push(&self.element)?;
+        let ret = self.element.session.execute_script_with_args(TABLE_SCRIPT, &args)?;
+        ret.convert()
+    }
+
+    /// Return the text of the header cells, or an empty Vec if the table has no header row.
+    pub fn headers(&self) -> WebDriverResult<Vec<String>> {
+        Ok(self.contents()?.headers)
+    }
+
+    /// Return the text of each cell in each row, excluding the header row.
+    pub fn rows(&self) -> WebDriverResult<Vec<Vec<String>>> {
+        Ok(self.contents()?.rows)
+    }
+
+    /// Return the text of the cell at the specified (zero-based) row and column. Rows are
+    /// numbered in the same way as `rows()`, i.e. excluding the header row.
+    ///
+    /// Returns `WebDriverError::NoSuchElement` if there is no cell at that position.
+    pub fn cell(&self, row: usize, col: usize) -> WebDriverResult<String> {
+        let rows = self.contents()?.rows;
+        rows.get(row).and_then(|cells| cells.get(col)).cloned().ok_or_else(|| {
+            no_such_element(&format!("No table cell at row {}, column {}", row, col))
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use crate::common::config::WebDriverConfig;
+use crate::error::WebDriverResult;
+
+/// Key under which the screenshot-on-error directory is stored in the config.
+const SCREENSHOT_ON_ERROR_DIR: &str = "thirtyfour_sync.screenshot_on_error_dir";
+
+/// Key under which the command timing flag is stored in the config.
+const COMMAND_TIMING: &str = "thirtyfour_sync.command_timing";
+
+/// Helper methods for the settings this crate stores in `WebDriverConfig`.
+///
+/// The config is per session, so these settings are shared by every command sent via the
+/// driver and its elements, and are copied along with the rest of the config when the
+/// session handle is cloned.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// # use std::path::PathBuf;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// let caps = DesiredCapabilities::chrome();
+/// let mut driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// driver.config_mut().set_screenshot_on_error(Some(PathBuf::from("target/screenshots")))?;
+/// driver.get("http://webappdemo")?;
+/// // This will fail, and save a screenshot into target/screenshots.
+/// assert!(driver.execute_script("throw new Error('oops');").is_err());
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+pub trait WebDriverConfigExt {
+    /// Automatically save a screenshot to the specified directory whenever a command returns
+    /// an unexpected error, or `None` to disable this.
+    ///
+    /// Screenshots are named `error-{session_id}-{timestamp}.png`. Failures while taking or
+    /// saving the screenshot are logged and otherwise ignored, so the original error is always
+    /// returned. No screenshot is taken for `NoSuchElement` and `NoSuchFrame` errors, since
+    /// these are routinely returned while polling (e.g. by `query()` or `wait_until()`), nor
+    /// if the failed command was itself a screenshot, or was deleting the session.
+    fn set_screenshot_on_error(&mut self, dir: Option<PathBuf>) -> WebDriverResult<()>;
+
+    /// Get the directory where screenshots are saved when a command fails, if enabled.
+    fn screenshot_on_error_dir(&self) -> Option<PathBuf>;
+
+    /// Start or stop recording the duration of each command sent to the WebDriver server.
+    ///
+    /// Timings are aggregated per type of command, and can be retrieved via
+    /// `WebDriver::command_timings()`. Command timing is disabled by default, so there is no
+    /// overhead unless it is enabled. Disabling it keeps the timings recorded so far; use
+    /// `WebDriverSession::reset_command_timings()` to discard them.
+    fn set_command_timing(&mut self, enabled: bool) -> WebDriverResult<()>;
+
+    /// Return true if the duration of each command is being recorded.
+    fn is_timing_commands(&self) -> bool;
+}
+
+impl WebDriverConfigExt for WebDriverConfig {
+    fn set_screenshot_on_error(&mut self, dir: Option<PathBuf>) -> WebDriverResult<()> {
+        self.set(SCREENSHOT_ON_ERROR_DIR, dir)
+    }
+
+    fn screenshot_on_error_dir(&self) -> Option<PathBuf> {
+        self.get::<Option<PathBuf>>(SCREENSHOT_ON_ERROR_DIR).ok().flatten().flatten()
+    }
+
+    fn set_command_timing(&mut self, enabled: bool) -> WebDriverResult<()> {
+        self.set(COMMAND_TIMING, enabled)
+    }
+
+    fn is_timing_commands(&self) -> bool {
+        self.get::<bool>(COMMAND_TIMING).ok().flatten().unwrap_or(false)
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+use crate::error::{WebDriverError, WebDriverResult};
+
+/// An event received over a WebDriver BiDi connection.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BiDiEvent {
+    /// The event name, e.g. `log.entryAdded`.
+    pub method: String,
+    /// The event parameters.
+    pub params: Value,
+}
+
+fn bidi_error(e: tungstenite::Error) -> WebDriverError {
+    WebDriverError::RequestFailed(format!("WebDriver BiDi connection error: {}", e))
+}
+
+/// Synchronous WebDriver BiDi connection, for receiving events from the browser.
+///
+/// WebDriver BiDi is a bidirectional protocol that runs over a WebSocket alongside the
+/// HTTP connection. This connection supports subscribing to events such as
+/// `log.entryAdded`, and then collecting the events into a buffer which you can drain
+/// whenever convenient. Events are only read from the WebSocket while a method on this
+/// connection is running, typically `collect_events()`.
+///
+/// This requires the `bidi` cargo feature, and a WebDriver server that supports BiDi.
+/// You also need to request the `webSocketUrl` capability when creating the session.
+/// Only unencrypted (`ws://`) connections are supported, which is what WebDriver servers
+/// normally provide.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use std::time::Duration;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let mut caps = DesiredCapabilities::firefox();
+/// caps.set_capability("webSocketUrl", serde_json::json!(true));
+/// let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// let mut bidi = driver.bidi_connect()?;
+/// bidi.subscribe("log.entryAdded")?;
+/// driver.get("http://webappdemo")?;
+/// driver.execute_script(r#"console.log("hello from the page");"#)?;
+/// bidi.collect_events(Duration::from_secs(1))?;
+/// let events = bidi.drain_events();
+/// #     assert!(events.iter().any(|e| e.params["text"] == "hello from the page"));
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BiDiConnectionSync {
+    socket: WebSocket<MaybeTlsStream<TcpStream>>,
+    next_id: u64,
+    events: VecDeque<BiDiEvent>,
+    command_timeout: Duration,
+}
+
+impl BiDiConnectionSync {
+    /// Connect to the specified WebSocket URL. This is the `webSocketUrl` capability
+    /// returned by the WebDriver server when the session was created.
+    pub fn connect(url: &str) -> WebDriverResult<Self> {
+        let (socket, _) = tungstenite::connect(url).map_err(bidi_error)?;
+        Ok(BiDiConnectionSync {
+            socket,
+            next_id: 1,
+            events: VecDeque::new(),
+            command_timeout: Duration::from_secs(30),
+        })
+    }
+
+    /// Set how long to wait for the response to a command. The default is 30 seconds.
+    pub fn set_command_timeout(&mut self, timeout: Duration) {
+        self.command_timeout = timeout;
+    }
+
+    /// Send the specified BiDi command and wait for its result.
+    ///
+    /// Any events received while waiting are added to the event buffer. If the command
+    /// fails, the error is converted into a `WebDriverError` in the same way as errors
+    /// returned by the HTTP connection.
+    pub fn send_command(&mut self, method: &str, params: Value) -> WebDriverResult<Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let command = json!({ "id": id, "method": method, "params": params });
+        self.socket.write_message(Message::Text(command.to_string())).map_err(bidi_error)?;
+
+        let deadline = Instant::now() + self.command_timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::ZERO {
+                return Err(WebDriverError::Timeout(format!(
+                    "Timed out waiting for response to BiDi command: {}",
+                    method
+                )));
+            }
+
+            match self.read_message(remaining)? {
+                Some(response) if response["id"] == id => {
+                    return match response["type"].as_str() {
+                        Some("error") => Err(WebDriverError::parse(
+                            500,
+                            json!({
+                                "value": {
+                                    "error": response["error"],
+                                    "message": response["message"],
+                                    "stacktrace": response["stacktrace"],
+                                }
+                            })
+                            .to_string(),
+                        )),
+                        _ => Ok(response["result"].clone()),
+                    };
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Subscribe to the specified event, e.g. `log.entryAdded` or
+    /// `network.beforeRequestSent`, for all browsing contexts.
+    pub fn subscribe(&mut self, event: &str) -> WebDriverResult<()> {
+        self.send_command("session.subscribe", json!({ "events": [event] }))?;
+        Ok(())
+    }
+
+    /// Unsubscribe from the specified event. Events that were already received remain in
+    /// the buffer.
+    pub fn unsubscribe(&mut self, event: &str) -> WebDriverResult<()> {
+        self.send_command("session.unsubscribe", json!({ "events": [event] }))?;
+        Ok(())
+    }
+
+    /// Block for the specified duration, adding any events received to the buffer.
+    /// Returns the number of events received.
+    pub fn collect_events(&mut self, duration: Duration) -> WebDriverResult<usize> {
+        let count = self.events.len();
+        let deadline = Instant::now() + duration;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::ZERO {
+                return Ok(self.events.len() - count);
+            }
+            self.read_message(remaining)?;
+        }
+    }
+
+    /// Remove and return all events in the buffer, oldest first.
+    pub fn drain_events(&mut self) -> Vec<BiDiEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Close the connection.
+    pub fn close(mut self) -> WebDriverResult<()> {
+        self.socket.close(None).map_err(bidi_error)
+    }
+
+    /// Read one message, waiting up to the specified timeout. Events are added to the
+    /// buffer, and any other JSON message is returned. Returns `None` on timeout, or if the
+    /// message was an event or not JSON.
+    fn read_message(&mut self, timeout: Duration) -> WebDriverResult<Option<Value>> {
+        match self.socket.get_mut() {
+            MaybeTlsStream::Plain(stream) => stream.set_read_timeout(Some(timeout))?,
+            _ => {
+                return Err(WebDriverError::RequestFailed(
+                    "WebDriver BiDi is only supported over unencrypted (ws://) connections"
+                        .to_string(),
+                ))
+            }
+        }
+
+        let text = match self.socket.read_message() {
+            Ok(Message::Text(text)) => text,
+            Ok(_) => return Ok(None),
+            Err(tungstenite::Error::Io(e))
+                if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut =>
+            {
+                return Ok(None)
+            }
+            Err(e) => return Err(bidi_error(e)),
+        };
+
+        let message: Value = match serde_json::from_str(&text) {
+            Ok(message) => message,
+            Err(_) => return Ok(None),
+        };
+        if message["type"] == "event"
+            || (message.get("id").is_none() && message["method"].is_string())
+        {
+            if let Ok(event) = serde_json::from_value(message) {
+                self.events.push_back(event);
+            }
+            return Ok(None);
+        }
+        Ok(Some(message))
+    }
+}
@@ -2,7 +2,16 @@ use std::fmt::Debug;
 
 use crate::error::WebDriverResult;
 use std::time::Duration;
-use thirtyfour::RequestData;
+use thirtyfour::{RequestData, RequestMethod};
+
+/// Get the HTTP method name for the specified request method, e.g. `"GET"`.
+pub(crate) fn request_method_name(method: &RequestMethod) -> &'static str {
+    match method {
+        RequestMethod::Get => "GET",
+        RequestMethod::Post => "POST",
+        RequestMethod::Delete => "DELETE",
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct HttpClientCreateParams {
@@ -25,5 +34,13 @@ pub trait WebDriverHttpClientSync: Debug + Send + Sync {
 
     fn set_request_timeout(&mut self, timeout: Duration);
 
+    /// Get the current HTTP request timeout, if known.
+    ///
+    /// This is used to restore the timeout after a request made via
+    /// `WebDriverSession::execute_with_timeout()`, which fails if this returns `None`.
+    fn request_timeout(&self) -> Option<Duration> {
+        None
+    }
+
     fn execute(&self, request_data: RequestData) -> WebDriverResult<serde_json::Value>;
 }
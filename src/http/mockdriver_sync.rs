@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::error::{WebDriverError, WebDriverErrorInfo, WebDriverResult};
+use crate::http::connection_sync::{
+    request_method_name, HttpClientCreateParams, WebDriverHttpClientSync,
+};
+use thirtyfour::RequestData;
+
+/// The session id returned by the default `NewSession` response.
+pub const MOCK_SESSION_ID: &str = "mock-session";
+
+/// Get the request path relative to the session, i.e. with the leading `/session/{id}` removed.
+fn session_path(url: &str) -> &str {
+    match url.strip_prefix("/session/") {
+        Some(rest) => rest.find('/').map(|i| &rest[i..]).unwrap_or(""),
+        None => url,
+    }
+}
+
+/// HTTP client that returns canned responses instead of talking to a WebDriver server.
+///
+/// This allows page objects and other code built on top of `WebDriver` to be unit-tested
+/// without a real browser. Responses are keyed by the HTTP method and the request path
+/// relative to the session, e.g. `("GET", "/title")` or `("POST", "/element")`. The path
+/// for the session itself (used by `DeleteSession`) is the empty string.
+///
+/// The value supplied for each response is the contents of the `value` field of the
+/// WebDriver response. Default responses are provided for creating a session, setting the
+/// timeouts and deleting the session, so that a `GenericWebDriver` can be created and
+/// dropped without any setup. Any other request without a registered response returns
+/// `WebDriverError::UnknownCommand`.
+///
+/// Error responses can be added using `add_error_response()`. These are parsed in the same
+/// way as errors from a real WebDriver server, so you can test how your code handles
+/// errors such as `WebDriverError::JavascriptError` or `WebDriverError::NoSuchElement`.
+///
+/// Clones of a `MockDriverSync` share the same responses, so you can keep a clone to add
+/// more responses after creating the driver. The request timeout is not shared; it is
+/// stored only so that it can be reported via `request_timeout()`, and has no effect.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use thirtyfour_sync::http::mockdriver_sync::MockDriverSync;
+/// use thirtyfour_sync::GenericWebDriver;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let mock = MockDriverSync::new();
+/// mock.add_response("GET", "/title", serde_json::json!("Demo Web App"));
+/// let caps = DesiredCapabilities::chrome();
+/// let driver = GenericWebDriver::new_with_client(mock.clone(), &caps)?;
+/// assert_eq!(driver.title()?, "Demo Web App");
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockDriverSync {
+    responses: Arc<Mutex<HashMap<(String, String), MockReply>>>,
+    timeout: Duration,
+}
+
+/// A canned response, either successful or an error with its HTTP status.
+#[derive(Debug, Clone)]
+enum MockReply {
+    Value(Value),
+    Error(u16, Value),
+}
+
+impl Default for MockDriverSync {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockDriverSync {
+    /// Create a new MockDriverSync with only the default responses.
+    pub fn new() -> Self {
+        let mock = MockDriverSync {
+            responses: Arc::new(Mutex::new(HashMap::new())),
+            timeout: Duration::from_secs(120),
+        };
+        mock.add_response(
+            "POST",
+            "/session",
+            json!({ "sessionId": MOCK_SESSION_ID, "capabilities": {} }),
+        );
+        mock.add_response("POST", "/timeouts", Value::Null);
+        mock.add_response("DELETE", "", Value::Null);
+        mock
+    }
+
+    /// Set the response for the specified HTTP method (`"GET"`, `"POST"` or `"DELETE"`) and
+    /// path relative to the session. This replaces any existing response for that request.
+    pub fn add_response(&self, method: &str, path: &str, value: Value) {
+        let mut responses = self.responses.lock().expect("mock responses lock poisoned");
+        responses.insert((method.to_uppercase(), path.to_string()), MockReply::Value(value));
+    }
+
+    /// Set an error response for the specified HTTP method and path relative to the session.
+    /// This replaces any existing response for that request.
+    ///
+    /// The value is the contents of the `value` field of the WebDriver error response, i.e.
+    /// an object containing `error`, `message` and optionally `stacktrace`. It is converted
+    /// into a `WebDriverError` in the same way as a response from a real WebDriver server.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// use thirtyfour_sync::error::WebDriverError;
+    /// use thirtyfour_sync::http::mockdriver_sync::MockDriverSync;
+    /// use thirtyfour_sync::GenericWebDriver;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mock = MockDriverSync::new();
+    /// mock.add_error_response(
+    ///     "POST",
+    ///     "/execute/sync",
+    ///     500,
+    ///     serde_json::json!({
+    ///         "error": "javascript error",
+    ///         "message": "javascript error: oops",
+    ///         "stacktrace": "Error: oops\n    at <anonymous>:1:7"
+    ///     }),
+    /// );
+    /// let caps = DesiredCapabilities::chrome();
+    /// let driver = GenericWebDriver::new_with_client(mock, &caps)?;
+    /// match driver.execute_script(r#"throw new Error("oops");"#) {
+    ///     Err(WebDriverError::JavascriptError(info)) => {
+    ///         assert!(info.value.message.contains("oops"));
+    ///         assert!(info.value.stacktrace.unwrap_or_default().contains("Error: oops"));
+    ///     }
+    ///     other => panic!("expected JavascriptError, got {:?}", other.map(|_| ())),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn add_error_response(&self, method: &str, path: &str, status: u16, value: Value) {
+        let mut responses = self.responses.lock().expect("mock responses lock poisoned");
+        responses
+            .insert((method.to_uppercase(), path.to_string()), MockReply::Error(status, value));
+    }
+
+    /// Remove all responses, including the defaults.
+    pub fn clear_responses(&self) {
+        let mut responses = self.responses.lock().expect("mock responses lock poisoned");
+        responses.clear();
+    }
+}
+
+impl WebDriverHttpClientSync for MockDriverSync {
+    fn create(params: HttpClientCreateParams) -> WebDriverResult<Self> {
+        let mut mock = MockDriverSync::new();
+        if let Some(timeout) = params.timeout {
+            mock.timeout = timeout;
+        }
+        Ok(mock)
+    }
+
+    fn set_request_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn execute(&self, request_data: RequestData) -> WebDriverResult<Value> {
+        let method = request_method_name(&request_data.method);
+        let path = session_path(&request_data.url);
+        let responses =
+            self.responses.lock().map_err(|e| WebDriverError::RequestFailed(e.to_string()))?;
+        match responses.get(&(method.to_string(), path.to_string())) {
+            Some(MockReply::Value(value)) => Ok(json!({ "value": value })),
+            Some(MockReply::Error(status, value)) => {
+                Err(WebDriverError::parse(*status, json!({ "value": value }).to_string()))
+            }
+            None => Err(WebDriverError::UnknownCommand(WebDriverErrorInfo::new(&format!(
+                "No mock response for {} {}",
+                method, path
+            )))),
+        }
+    }
+}
+
+/// Create a driver for unit tests that uses a clone of the specified mock.
+#[cfg(test)]
+pub(crate) fn mock_driver(mock: &MockDriverSync) -> crate::GenericWebDriver<MockDriverSync> {
+    let caps = crate::DesiredCapabilities::chrome();
+    crate::GenericWebDriver::new_with_client(mock.clone(), &caps).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::command::Command;
+    use crate::prelude::*;
+    use crate::GenericWebDriver;
+
+    #[test]
+    fn session_path_is_relative_to_session() {
+        assert_eq!(session_path("/session"), "/session");
+        assert_eq!(session_path("/session/abc"), "");
+        assert_eq!(session_path("/session/abc/title"), "/title");
+        assert_eq!(session_path("/session/abc/element/e-1/text"), "/element/e-1/text");
+    }
+
+    #[test]
+    fn canned_response() {
+        let mock = MockDriverSync::new();
+        let driver = mock_driver(&mock);
+        assert_eq!(driver.session_id().to_string(), MOCK_SESSION_ID);
+
+        // Responses added after creating the driver are shared with it.
+        mock.add_response("GET", "/title", json!("Demo Web App"));
+        assert_eq!(driver.title().unwrap(), "Demo Web App");
+        mock.add_response("get", "/title", json!("Replaced"));
+        assert_eq!(driver.title().unwrap(), "Replaced");
+    }
+
+    #[test]
+    fn error_response() {
+        let mock = MockDriverSync::new();
+        mock.add_error_response(
+            "POST",
+            "/execute/sync",
+            500,
+            json!({
+                "error": "javascript error",
+                "message": "javascript error: oops",
+                "stacktrace": "Error: oops\n    at <anonymous>:1:7"
+            }),
+        );
+        let driver = mock_driver(&mock);
+        match driver.execute_script(r#"throw new Error("oops");"#) {
+            Err(WebDriverError::JavascriptError(info)) => {
+                assert_eq!(info.value.message, "javascript error: oops");
+                assert_eq!(
+                    info.value.stacktrace.as_deref(),
+                    Some("Error: oops\n    at <anonymous>:1:7")
+                );
+            }
+            other => panic!("expected JavascriptError, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn unmatched_request() {
+        let mock = MockDriverSync::new();
+        let driver = mock_driver(&mock);
+        match driver.current_url() {
+            Err(WebDriverError::UnknownCommand(info)) => {
+                assert_eq!(info.value.message, "No mock response for GET /url");
+            }
+            other => panic!("expected UnknownCommand, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn clear_responses_removes_defaults() {
+        let mock = MockDriverSync::new();
+        mock.clear_responses();
+        let caps = DesiredCapabilities::chrome();
+        assert!(GenericWebDriver::new_with_client(mock, &caps).is_err());
+    }
+
+    #[test]
+    fn execute_with_timeout_is_supported() {
+        let mock = MockDriverSync::new();
+        mock.add_response("GET", "/title", json!("Demo Web App"));
+        let driver = mock_driver(&mock);
+        let ret = driver
+            .session
+            .execute_with_timeout(Box::new(Command::GetTitle), Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(ret["value"], "Demo Web App");
+    }
+}
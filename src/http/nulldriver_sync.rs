@@ -1,32 +1,35 @@
 use std::fmt::Debug;
+use std::time::Duration;
 
+use crate::error::WebDriverResult;
 use crate::http::connection_sync::{HttpClientCreateParams, WebDriverHttpClientSync};
-use crate::{
-    common::command::{Command, RequestMethod},
-    error::{WebDriverError, WebDriverResult},
-    SessionId,
-};
+use thirtyfour::RequestData;
 
 /// Null driver that satisfies the build but does nothing.
+///
+/// Every request returns `serde_json::Value::Null`. For a client that returns canned
+/// responses, see [MockDriverSync](../mockdriver_sync/struct.MockDriverSync.html).
 #[derive(Debug)]
 pub struct NullDriverSync {
-    url: String,
+    timeout: Duration,
 }
 
 impl WebDriverHttpClientSync for NullDriverSync {
     fn create(params: HttpClientCreateParams) -> WebDriverResult<Self> {
         Ok(NullDriverSync {
-            url: params.server_url.to_string(),
+            timeout: params.timeout.unwrap_or_else(|| Duration::from_secs(120)),
         })
     }
 
-    fn set_request_timeout(&mut self, _timeout: Duration) {}
+    fn set_request_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
 
-    fn execute(
-        &self,
-        _session_id: &SessionId,
-        _command: Command<'_>,
-    ) -> WebDriverResult<serde_json::Value> {
+    fn execute(&self, _request_data: RequestData) -> WebDriverResult<serde_json::Value> {
         Ok(serde_json::Value::Null)
     }
 }
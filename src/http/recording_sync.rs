@@ -0,0 +1,133 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::error::WebDriverResult;
+use crate::http::connection_sync::{
+    request_method_name, HttpClientCreateParams, WebDriverHttpClientSync,
+};
+use thirtyfour::RequestData;
+
+/// A single request recorded by [RecordingDriverSync](struct.RecordingDriverSync.html),
+/// along with its response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    /// The HTTP method, e.g. `"GET"`.
+    pub method: String,
+    /// The request URL, relative to the WebDriver server, e.g. `"/session/{id}/title"`.
+    pub url: String,
+    /// The request body, if any.
+    pub body: Option<Value>,
+    /// The response, or the error message if the request failed.
+    pub response: Result<Value, String>,
+}
+
+/// Shared handle to the log of requests recorded by a
+/// [RecordingDriverSync](struct.RecordingDriverSync.html).
+#[derive(Debug, Clone, Default)]
+pub struct RequestLog {
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl RequestLog {
+    /// Get a copy of all requests recorded so far, in the order they were made.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("request log lock poisoned").clone()
+    }
+
+    /// Clear all recorded requests.
+    pub fn clear(&self) {
+        self.requests.lock().expect("request log lock poisoned").clear();
+    }
+
+    fn push(&self, request: RecordedRequest) {
+        self.requests.lock().expect("request log lock poisoned").push(request);
+    }
+}
+
+/// HTTP client that wraps another client and records every request and response.
+///
+/// This is useful for asserting exactly which WebDriver commands were issued, for example
+/// to check that a caching layer avoided redundant requests. Get a handle to the log via
+/// `log()` before passing the client to `GenericWebDriver::new_with_client()`.
+///
+/// If created via `GenericWebDriver::new()` or similar, the inner client is created using
+/// the same parameters, but there is then no way to access the log.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use thirtyfour_sync::http::mockdriver_sync::MockDriverSync;
+/// use thirtyfour_sync::http::recording_sync::RecordingDriverSync;
+/// use thirtyfour_sync::GenericWebDriver;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let mock = MockDriverSync::new();
+/// mock.add_response("GET", "/title", serde_json::json!("Demo Web App"));
+/// let recorder = RecordingDriverSync::new(mock);
+/// let log = recorder.log();
+/// let caps = DesiredCapabilities::chrome();
+/// let driver = GenericWebDriver::new_with_client(recorder, &caps)?;
+/// log.clear();
+/// driver.title()?;
+/// let requests = log.requests();
+/// assert_eq!(requests.len(), 1);
+/// assert_eq!(requests[0].method, "GET");
+/// assert_eq!(requests[0].url, "/session/mock-session/title");
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RecordingDriverSync<T: WebDriverHttpClientSync> {
+    inner: T,
+    log: RequestLog,
+}
+
+impl<T: WebDriverHttpClientSync> RecordingDriverSync<T> {
+    /// Create a new RecordingDriverSync that wraps the specified client.
+    pub fn new(inner: T) -> Self {
+        RecordingDriverSync {
+            inner,
+            log: RequestLog::default(),
+        }
+    }
+
+    /// Get a handle to the log of recorded requests. The handle remains valid after this
+    /// client has been moved into a `GenericWebDriver`.
+    pub fn log(&self) -> RequestLog {
+        self.log.clone()
+    }
+}
+
+impl<T: WebDriverHttpClientSync> WebDriverHttpClientSync for RecordingDriverSync<T> {
+    fn create(params: HttpClientCreateParams) -> WebDriverResult<Self> {
+        Ok(RecordingDriverSync::new(T::create(params)?))
+    }
+
+    fn set_request_timeout(&mut self, timeout: Duration) {
+        self.inner.set_request_timeout(timeout);
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.inner.request_timeout()
+    }
+
+    fn execute(&self, request_data: RequestData) -> WebDriverResult<Value> {
+        let method = request_method_name(&request_data.method).to_string();
+        let url = request_data.url.clone();
+        let body = request_data.body.clone();
+        let result = self.inner.execute(request_data);
+        self.log.push(RecordedRequest {
+            method,
+            url,
+            body,
+            response: match &result {
+                Ok(v) => Ok(v.clone()),
+                Err(e) => Err(e.to_string()),
+            },
+        });
+        result
+    }
+}
@@ -32,6 +32,11 @@ impl WebDriverHttpClientSync for ReqwestDriverSync {
         self.timeout = timeout;
     }
 
+    /// Get the HTTP client request timeout.
+    fn request_timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
     /// Execute the specified command and return the data as serde_json::Value.
     fn execute(&self, request_data: RequestData) -> WebDriverResult<serde_json::Value> {
         let url = self.url.clone() + &request_data.url;
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+/// Javascript that registers handlers (once per document) which record uncaught errors and
+/// unhandled promise rejections in `window.__thirtyfourErrors`.
+pub(crate) const INSTALL_ERROR_COLLECTOR_SCRIPT: &str = r#"
+if (!window.__thirtyfourErrors) {
+    window.__thirtyfourErrors = [];
+    window.addEventListener("error", (event) => {
+        window.__thirtyfourErrors.push({
+            message: event.message || String(event.error),
+            source: event.filename || null,
+            line: event.lineno || null,
+            column: event.colno || null,
+            stack: event.error && event.error.stack ? String(event.error.stack) : null
+        });
+    });
+    window.addEventListener("unhandledrejection", (event) => {
+        let reason = event.reason;
+        window.__thirtyfourErrors.push({
+            message: "Unhandled promise rejection: " +
+                (reason && reason.message ? reason.message : String(reason)),
+            source: null,
+            line: null,
+            column: null,
+            stack: reason && reason.stack ? String(reason.stack) : null
+        });
+    });
+}
+"#;
+
+/// Javascript that returns and clears the errors recorded by the error collector, or null
+/// if the collector is not installed on the current page.
+pub(crate) const COLLECT_ERRORS_SCRIPT: &str = r#"
+let errors = window.__thirtyfourErrors;
+if (!errors) {
+    return null;
+}
+window.__thirtyfourErrors = [];
+return errors;
+"#;
+
+/// An uncaught Javascript error, as returned by
+/// [WebDriver::collect_js_errors()](struct.WebDriver.html#method.collect_js_errors).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct JsError {
+    /// The error message.
+    pub message: String,
+    /// The URL of the script that threw the error, if known.
+    pub source: Option<String>,
+    /// The line number where the error was thrown, if known.
+    pub line: Option<u32>,
+    /// The column number where the error was thrown, if known.
+    pub column: Option<u32>,
+    /// The stack trace, if available.
+    pub stack: Option<String>,
+}
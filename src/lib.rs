@@ -31,6 +31,10 @@
 //! - **reqwest-native-tls-vendored**: Enables the `vendored` feature of `native-tls`.
 //! - **reqwest-rustls-tls**: Enables TLS functionality provided by `rustls`.
 //!
+//! There is also an optional `bidi` feature:
+//! - **bidi**: Enables a minimal WebDriver BiDi connection for subscribing to browser events.
+//!   See [BiDiConnectionSync](http/bidi_sync/struct.BiDiConnectionSync.html).
+//!
 //! ## Examples
 //!
 //! The following example assumes you have a selenium server running
@@ -116,18 +120,32 @@ pub use thirtyfour::common::{
 pub use thirtyfour::error;
 pub use thirtyfour::SessionId;
 
+pub use accessibility::AccessibilityNode;
 pub use alert::Alert;
+pub use capabilities::{CapabilitiesExt, ChromeCapabilitiesExt, FirstMatchCapabilities};
+pub use config::WebDriverConfigExt;
+pub use js_errors::JsError;
+pub use links::{Link, LinkOptions};
+pub use network::{MockResponse, NetworkInterceptor};
+pub use performance::PerformanceTiming;
+pub use rect::RectExt;
 pub use session::WebDriverSession;
 pub use switch_to::SwitchTo;
+pub use timing::CommandTiming;
+pub use validity::ValidityState;
+pub use viewport::ViewportMetrics;
 pub use webdriver::GenericWebDriver;
 pub use webdriver::WebDriver;
-pub use webdrivercommands::WebDriverCommands;
+pub use webdrivercommands::{ColorScheme, WebDriverCommands, WindowHandleType};
 pub use webelement::WebElement;
 
 pub mod prelude {
     pub use crate::alert::Alert;
+    pub use crate::capabilities::CapabilitiesExt;
+    pub use crate::config::WebDriverConfigExt;
     pub use crate::error::WebDriverResult;
     pub use crate::query::{ElementQueryable, ElementWaitable};
+    pub use crate::rect::RectExt;
     pub use crate::switch_to::SwitchTo;
     pub use crate::webdriver::WebDriver;
     pub use crate::webdrivercommands::{ScriptRetSync, WebDriverCommands};
@@ -135,14 +153,31 @@ pub mod prelude {
     pub use thirtyfour::{By, Cookie, DesiredCapabilities, Keys, ScriptArgs, TypingData};
 }
 
+mod accessibility;
 pub mod action_chain;
 mod alert;
+mod capabilities;
+mod config;
 pub mod http {
+    #[cfg(feature = "bidi")]
+    pub mod bidi_sync;
     pub mod connection_sync;
+    pub mod mockdriver_sync;
+    pub mod nulldriver_sync;
+    pub mod recording_sync;
     pub mod reqwest_sync;
 }
+mod js_errors;
+mod links;
+mod network;
+mod performance;
+mod rect;
+mod roles;
 mod session;
 mod switch_to;
+mod timing;
+mod validity;
+mod viewport;
 mod webdriver;
 mod webdrivercommands;
 mod webelement;
@@ -160,8 +195,12 @@ pub mod extensions {
 
 /// Wrappers for specific component types.
 pub mod components {
+    /// Wrapper for `<input>` elements backed by a `<datalist>`.
+    pub mod datalist;
     /// Wrapper for `<select>` elements.
     pub mod select;
+    /// Wrapper for `<table>` elements.
+    pub mod table;
 }
 
 // ElementQuery and ElementWaiter interfaces.
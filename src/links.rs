@@ -0,0 +1,78 @@
+use reqwest::blocking::Client;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+/// Javascript that returns the text and resolved URL of every `<a href>` on the page.
+/// The flags in `arguments[0]` and `arguments[1]` control whether empty and `javascript:`
+/// hrefs are included.
+pub(crate) const ALL_LINKS_SCRIPT: &str = r#"
+let includeEmpty = arguments[0];
+let includeJavascript = arguments[1];
+return Array.from(document.querySelectorAll("a[href]"))
+    .filter((a) => includeEmpty || a.getAttribute("href").trim() !== "")
+    .filter((a) => includeJavascript || a.protocol !== "javascript:")
+    .map((a) => ({ text: (a.innerText || a.textContent || "").trim(), href: a.href }));
+"#;
+
+/// A link on the page, as returned by
+/// [WebDriver::all_links_with_text()](struct.WebDriver.html#method.all_links_with_text).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Link {
+    /// The visible text of the link, with leading and trailing whitespace removed.
+    pub text: String,
+    /// The URL of the link, resolved against the base URL of the document.
+    pub href: String,
+}
+
+/// Options for filtering the links returned by
+/// [WebDriver::all_links_with_text()](struct.WebDriver.html#method.all_links_with_text).
+///
+/// By default, links with an empty `href` (which refer to the current page) and links
+/// with a `javascript:` URL are excluded.
+///
+/// # Example:
+/// ```rust
+/// use thirtyfour_sync::LinkOptions;
+///
+/// let options = LinkOptions::new().include_empty(true);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LinkOptions {
+    pub(crate) include_empty: bool,
+    pub(crate) include_javascript: bool,
+}
+
+impl LinkOptions {
+    /// Create a new LinkOptions with the default filtering.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include links with an empty `href` attribute.
+    pub fn include_empty(mut self, include: bool) -> Self {
+        self.include_empty = include;
+        self
+    }
+
+    /// Include links with a `javascript:` URL.
+    pub fn include_javascript(mut self, include: bool) -> Self {
+        self.include_javascript = include;
+        self
+    }
+}
+
+/// Get the HTTP status code for the specified URL, or `None` if the request failed.
+///
+/// A HEAD request is tried first, since it avoids downloading the body. Some servers do not
+/// support HEAD, so a GET request is used if the HEAD request fails or is rejected.
+pub(crate) fn link_status(client: &Client, url: &str) -> Option<u16> {
+    match client.head(url).send() {
+        Ok(resp)
+            if resp.status() != StatusCode::METHOD_NOT_ALLOWED
+                && resp.status() != StatusCode::NOT_IMPLEMENTED =>
+        {
+            Some(resp.status().as_u16())
+        }
+        _ => client.get(url).send().ok().map(|resp| resp.status().as_u16()),
+    }
+}
@@ -0,0 +1,170 @@
+use serde_json::json;
+
+/// Javascript function that patches `fetch()` and `XMLHttpRequest` (once per document) to
+/// return mock responses for any URL matching a registered rule, and then registers the
+/// specified rule. A rule with the same URL pattern replaces any existing rule.
+const INSTALL_MOCK_FN: &str = r#"
+function(rule) {
+    if (!window.__thirtyfourMocks) {
+        window.__thirtyfourMocks = [];
+        let findRule = (url) => {
+            let href = new URL(String(url), location.href).href;
+            return window.__thirtyfourMocks.find((r) => new RegExp(r.regex).test(href));
+        };
+        let bodyFor = (rule) => ([101, 204, 205, 304].includes(rule.status) ? null : rule.body);
+
+        if (window.fetch) {
+            let originalFetch = window.fetch;
+            window.fetch = function(input, init) {
+                let rule = findRule(input && input.url ? input.url : input);
+                if (!rule) {
+                    return originalFetch.apply(this, arguments);
+                }
+                return Promise.resolve(
+                    new Response(bodyFor(rule), { status: rule.status, headers: rule.headers })
+                );
+            };
+        }
+
+        let originalOpen = XMLHttpRequest.prototype.open;
+        let originalSend = XMLHttpRequest.prototype.send;
+        XMLHttpRequest.prototype.open = function(method, url) {
+            this.__thirtyfourUrl = url;
+            return originalOpen.apply(this, arguments);
+        };
+        XMLHttpRequest.prototype.send = function() {
+            let rule = findRule(this.__thirtyfourUrl);
+            if (!rule) {
+                return originalSend.apply(this, arguments);
+            }
+            let xhr = this;
+            let body = bodyFor(rule) || "";
+            let headers = rule.headers.map((h) => h[0] + ": " + h[1]).join("\r\n");
+            let define = (name, value) => Object.defineProperty(xhr, name, { value: value });
+            define("readyState", 4);
+            define("status", rule.status);
+            define("responseText", body);
+            define("response", body);
+            define("getAllResponseHeaders", () => headers);
+            define("getResponseHeader", (name) => {
+                let header = rule.headers.find((h) => h[0].toLowerCase() === name.toLowerCase());
+                return header ? header[1] : null;
+            });
+            setTimeout(() => {
+                ["readystatechange", "load", "loadend"].forEach((t) => xhr.dispatchEvent(new Event(t)));
+            }, 0);
+        };
+    }
+
+    window.__thirtyfourMocks = window.__thirtyfourMocks.filter((r) => r.pattern !== rule.pattern);
+    window.__thirtyfourMocks.push(rule);
+}
+"#;
+
+/// A mock HTTP response, for use with
+/// [WebDriver::add_network_interceptor()](struct.WebDriver.html#method.add_network_interceptor).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response headers, as (name, value) pairs.
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: String,
+}
+
+impl MockResponse {
+    /// Create a new MockResponse with the specified status code and body, and no headers.
+    pub fn new<S: Into<String>>(status: u16, body: S) -> Self {
+        MockResponse {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Create a new MockResponse with status 200 and the specified JSON body.
+    /// The `Content-Type` header is set to `application/json`.
+    pub fn json(value: &serde_json::Value) -> Self {
+        MockResponse::new(200, value.to_string()).with_header("Content-Type", "application/json")
+    }
+
+    /// Add the specified header to the response.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Handle for a network interceptor added via
+/// [WebDriver::add_network_interceptor()](struct.WebDriver.html#method.add_network_interceptor).
+///
+/// Pass this to
+/// [WebDriver::remove_network_interceptor()](struct.WebDriver.html#method.remove_network_interceptor)
+/// to stop intercepting requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkInterceptor {
+    /// The URL pattern being intercepted.
+    pub url_pattern: String,
+    pub(crate) script_id: String,
+}
+
+/// Convert a URL pattern, where `*` matches zero or more characters, into an anchored regex.
+fn pattern_to_regex(url_pattern: &str) -> String {
+    let escaped: Vec<String> = url_pattern.split('*').map(regex_escape).collect();
+    format!("^{}$", escaped.join(".*"))
+}
+
+fn regex_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\^$.|?+()[]{}/".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Get the script that registers a mock response for the specified URL pattern.
+pub(crate) fn mock_script(url_pattern: &str, response: &MockResponse) -> String {
+    let rule = json!({
+        "pattern": url_pattern,
+        "regex": pattern_to_regex(url_pattern),
+        "status": response.status,
+        "headers": response.headers,
+        "body": response.body,
+    });
+    format!("({})({});", INSTALL_MOCK_FN, rule)
+}
+
+/// Get the script that removes the mock response for the specified URL pattern from the
+/// current page.
+pub(crate) fn unmock_script(url_pattern: &str) -> String {
+    format!(
+        "if (window.__thirtyfourMocks) {{ window.__thirtyfourMocks = \
+         window.__thirtyfourMocks.filter((r) => r.pattern !== {}); }}",
+        json!(url_pattern)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_to_regex_wildcards() {
+        assert_eq!(pattern_to_regex("*/api/users*"), r"^.*\/api\/users.*$");
+        assert_eq!(pattern_to_regex("*"), "^.*$");
+        assert_eq!(pattern_to_regex(""), "^$");
+    }
+
+    #[test]
+    fn pattern_to_regex_escapes_special_characters() {
+        assert_eq!(
+            pattern_to_regex("http://example.com/search?q=(a|b)"),
+            r"^http:\/\/example\.com\/search\?q=\(a\|b\)$"
+        );
+        assert_eq!(pattern_to_regex("/a+b/[1]/{x}$^\\"), r"^\/a\+b\/\[1\]\/\{x\}\$\^\\$");
+    }
+}
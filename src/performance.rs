@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Javascript that returns the page load timings in milliseconds, relative to the start of
+/// navigation. This prefers the Navigation Timing Level 2 API and falls back to the legacy
+/// `performance.timing` API for browsers that do not support it.
+pub(crate) const PERFORMANCE_TIMING_SCRIPT: &str = r#"
+let timing = performance.getEntriesByType
+    ? performance.getEntriesByType("navigation")[0]
+    : undefined;
+let start = 0;
+if (!timing) {
+    timing = performance.timing;
+    start = timing.navigationStart;
+}
+let since = (end, begin) => Math.max(0, (end || 0) - (begin || 0));
+let event = (end) => (end > 0 ? since(end, start) : null);
+return {
+    dnsLookup: since(timing.domainLookupEnd, timing.domainLookupStart),
+    tcpConnect: since(timing.connectEnd, timing.connectStart),
+    timeToFirstByte: since(timing.responseStart, start),
+    domContentLoaded: event(timing.domContentLoadedEventEnd),
+    load: event(timing.loadEventEnd)
+};
+"#;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawPerformanceTiming {
+    dns_lookup: f64,
+    tcp_connect: f64,
+    time_to_first_byte: f64,
+    dom_content_loaded: Option<f64>,
+    load: Option<f64>,
+}
+
+/// Page load timings for the current page, as returned by
+/// [WebDriver::performance_timing()](struct.WebDriver.html#method.performance_timing).
+///
+/// All durations from `time_to_first_byte` onwards are measured from the start of navigation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceTiming {
+    /// Time taken for the DNS lookup.
+    pub dns_lookup: Duration,
+    /// Time taken to establish the TCP connection (including TLS negotiation, if any).
+    pub tcp_connect: Duration,
+    /// Time until the first byte of the response was received.
+    pub time_to_first_byte: Duration,
+    /// Time until the `DOMContentLoaded` event completed, or `None` if it has not completed yet.
+    pub dom_content_loaded: Option<Duration>,
+    /// Time until the `load` event completed, or `None` if it has not completed yet.
+    pub load: Option<Duration>,
+}
+
+fn millis(ms: f64) -> Duration {
+    Duration::from_secs_f64(ms.max(0.0) / 1000.0)
+}
+
+impl From<RawPerformanceTiming> for PerformanceTiming {
+    fn from(raw: RawPerformanceTiming) -> Self {
+        PerformanceTiming {
+            dns_lookup: millis(raw.dns_lookup),
+            tcp_connect: millis(raw.tcp_connect),
+            time_to_first_byte: millis(raw.time_to_first_byte),
+            dom_content_loaded: raw.dom_content_loaded.map(millis),
+            load: raw.load.map(millis),
+        }
+    }
+}
@@ -462,3 +462,167 @@ where
         Ok(true)
     })
 }
+
+/// Predicate that returns the opposite of the specified predicate.
+///
+/// Any error returned by the inner predicate is passed through unchanged. Note that if the inner
+/// predicate ignores errors, it will return false on error, and so the negated predicate will
+/// return true. Where an explicit negation such as `element_is_not_displayed()` exists, prefer
+/// that instead, since it will return false on error when ignoring errors.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use thirtyfour_sync::query::conditions;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// #     let caps = DesiredCapabilities::chrome();
+/// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// #     driver.get("http://webappdemo")?;
+/// let elem = driver.find_element(By::Id("button1"))?;
+/// // Wait until the button no longer has the "disabled" class.
+/// let not_disabled = conditions::not(conditions::element_has_class("disabled", false));
+/// elem.wait_until().condition(not_disabled)?;
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+pub fn not(inner: ElementPredicate) -> ElementPredicate {
+    Box::new(move |elem| inner(elem).map(|x| !x))
+}
+
+/// Predicate that returns true only if all of the specified predicates return true.
+///
+/// The predicates are evaluated in order, and evaluation stops at the first predicate that
+/// returns false or an error, so that no further requests are made to the WebDriver server.
+/// If the list of predicates is empty, this returns true.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use thirtyfour_sync::query::conditions;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// #     let caps = DesiredCapabilities::chrome();
+/// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// #     driver.get("http://webappdemo")?;
+/// let elem = driver
+///     .query(By::Id("button1"))
+///     .with_filter(conditions::all_of(vec![
+///         conditions::element_is_displayed(true),
+///         conditions::element_is_enabled(true),
+///     ]))
+///     .first()?;
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+pub fn all_of(predicates: Vec<ElementPredicate>) -> ElementPredicate {
+    Box::new(move |elem| {
+        for predicate in &predicates {
+            if !predicate(elem)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    })
+}
+
+/// Predicate that returns true if any of the specified predicates return true.
+///
+/// The predicates are evaluated in order, and evaluation stops at the first predicate that
+/// returns true or an error, so that no further requests are made to the WebDriver server.
+/// If the list of predicates is empty, this returns false.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// use thirtyfour_sync::query::conditions;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// #     let caps = DesiredCapabilities::chrome();
+/// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// #     driver.get("http://webappdemo")?;
+/// let elem = driver.find_element(By::Id("button1"))?;
+/// elem.wait_until().condition(conditions::any_of(vec![
+///     conditions::element_has_class("active", true),
+///     conditions::element_is_enabled(true),
+/// ]))?;
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+pub fn any_of(predicates: Vec<ElementPredicate>) -> ElementPredicate {
+    Box::new(move |elem| {
+        for predicate in &predicates {
+            if predicate(elem)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WebDriverError;
+    use crate::http::mockdriver_sync::{mock_driver, MockDriverSync};
+    use crate::{ElementId, WebElement};
+
+    fn always(value: bool) -> ElementPredicate {
+        Box::new(move |_| Ok(value))
+    }
+
+    /// Predicate that returns an error, for checking that evaluation stops early.
+    fn never_evaluated() -> ElementPredicate {
+        Box::new(|_| Err(WebDriverError::RequestFailed("predicate should not be evaluated".into())))
+    }
+
+    #[test]
+    fn not_negates_and_passes_errors_through() {
+        let mock = MockDriverSync::new();
+        mock.add_response("GET", "/element/el-1/enabled", serde_json::json!(false));
+        let driver = mock_driver(&mock);
+        let elem = WebElement::new(&driver.session, ElementId::from("el-1".to_string()));
+        assert!(!not(always(true))(&elem).unwrap());
+        assert!(not(always(false))(&elem).unwrap());
+        assert!(not(never_evaluated())(&elem).is_err());
+        assert!(not(element_is_enabled(false))(&elem).unwrap());
+    }
+
+    #[test]
+    fn all_of_short_circuits() {
+        let driver = mock_driver(&MockDriverSync::new());
+        let elem = WebElement::new(&driver.session, ElementId::from("el-1".to_string()));
+        assert!(all_of(vec![])(&elem).unwrap());
+        assert!(all_of(vec![always(true), always(true)])(&elem).unwrap());
+        assert!(!all_of(vec![always(true), always(false), never_evaluated()])(&elem).unwrap());
+        assert!(all_of(vec![always(true), never_evaluated()])(&elem).is_err());
+    }
+
+    #[test]
+    fn any_of_short_circuits() {
+        let driver = mock_driver(&MockDriverSync::new());
+        let elem = WebElement::new(&driver.session, ElementId::from("el-1".to_string()));
+        assert!(!any_of(vec![])(&elem).unwrap());
+        assert!(!any_of(vec![always(false), always(false)])(&elem).unwrap());
+        assert!(any_of(vec![always(false), always(true), never_evaluated()])(&elem).unwrap());
+        assert!(any_of(vec![always(false), never_evaluated()])(&elem).is_err());
+    }
+
+    #[test]
+    fn all_of_and_any_of_with_element_conditions() {
+        let mock = MockDriverSync::new();
+        mock.add_response("GET", "/element/el-1/displayed", serde_json::json!(true));
+        mock.add_response("GET", "/element/el-1/enabled", serde_json::json!(false));
+        let driver = mock_driver(&mock);
+        let elem = WebElement::new(&driver.session, ElementId::from("el-1".to_string()));
+        let displayed_and_enabled =
+            all_of(vec![element_is_displayed(false), element_is_enabled(false)]);
+        assert!(!displayed_and_enabled(&elem).unwrap());
+        let displayed_or_enabled =
+            any_of(vec![element_is_enabled(false), element_is_displayed(false)]);
+        assert!(displayed_or_enabled(&elem).unwrap());
+    }
+}
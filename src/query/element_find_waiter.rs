@@ -0,0 +1,183 @@
+use crate::error::WebDriverError;
+use crate::prelude::WebDriverResult;
+use crate::query::{
+    conditions, poller_interval, ElementPoller, ElementPollerTicker, ElementPredicate,
+};
+use crate::{By, WebDriverCommands, WebDriverSession, WebElement};
+use std::time::Duration;
+
+/// High-level interface for finding an element and then waiting for it to satisfy a
+/// condition, using the builder pattern.
+///
+/// This combines `find_element()` with `ElementWaiter` in a single call. The element is
+/// searched for on every poll until it exists, and then the condition is checked. The same
+/// poller governs both steps, so the timeout applies to the whole operation.
+///
+/// If the element is replaced in the DOM while waiting, it will simply be found again on
+/// the next poll.
+///
+/// # Example:
+/// ```rust
+/// # use thirtyfour_sync::prelude::*;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// #     let caps = DesiredCapabilities::chrome();
+/// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+/// #     driver.get("http://webappdemo")?;
+/// // Wait until the element exists and is clickable, then click it.
+/// let elem = driver.wait_for_element(By::Id("button1")).clickable()?;
+/// elem.click()?;
+/// #     driver.quit()?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElementFindWaiter<'a> {
+    session: &'a WebDriverSession,
+    by: By<'a>,
+    poller: ElementPoller,
+    message: String,
+    ignore_errors: bool,
+}
+
+impl<'a> ElementFindWaiter<'a> {
+    pub(crate) fn new(session: &'a WebDriverSession, by: By<'a>, poller: ElementPoller) -> Self {
+        Self {
+            session,
+            by,
+            poller,
+            message: String::new(),
+            ignore_errors: true,
+        }
+    }
+
+    /// Use the specified ElementPoller for this ElementFindWaiter.
+    /// This will not affect the default ElementPoller used for other waits.
+    pub fn with_poller(mut self, poller: ElementPoller) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// Provide a human-readable error message to be returned in the case of timeout.
+    pub fn error(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// By default any errors that occur while checking the pre-defined conditions are
+    /// ignored. However, this behaviour can be modified so that the waiter will return
+    /// early if an error is returned from thirtyfour.
+    ///
+    /// Errors while searching for the element, other than the element not existing yet,
+    /// are always returned.
+    pub fn ignore_errors(mut self, ignore: bool) -> Self {
+        self.ignore_errors = ignore;
+        self
+    }
+
+    /// Force this ElementFindWaiter to wait for the specified timeout, polling once
+    /// after each interval. This will override the poller for this
+    /// ElementFindWaiter only.
+    pub fn wait(self, timeout: Duration, interval: Duration) -> Self {
+        self.with_poller(ElementPoller::TimeoutWithInterval(timeout, interval))
+    }
+
+    /// Force this ElementFindWaiter to wait for the specified timeout, keeping the polling
+    /// interval of the current poller.
+    pub fn within(self, timeout: Duration) -> Self {
+        let interval = poller_interval(&self.poller);
+        self.wait(timeout, interval)
+    }
+
+    fn run_poller(self, conditions: Vec<ElementPredicate>) -> WebDriverResult<WebElement<'a>> {
+        let mut ticker = ElementPollerTicker::new(self.poller.clone());
+        loop {
+            match self.session.find_element(self.by.clone()) {
+                Ok(elem) => {
+                    let mut conditions_met = true;
+                    for f in &conditions {
+                        if !f(&elem)? {
+                            conditions_met = false;
+                            break;
+                        }
+                    }
+
+                    if conditions_met {
+                        return Ok(elem);
+                    }
+                }
+                Err(WebDriverError::NoSuchElement(_)) => {}
+                Err(e) => return Err(e),
+            }
+
+            if !ticker.tick() {
+                return Err(self.timeout());
+            }
+        }
+    }
+
+    fn timeout(&self) -> WebDriverError {
+        if self.message.is_empty() {
+            WebDriverError::Timeout(format!(
+                "Timed out waiting for element using selector: {}",
+                self.by
+            ))
+        } else {
+            WebDriverError::Timeout(self.message.clone())
+        }
+    }
+
+    /// Wait until the element exists, and return it.
+    pub fn exists(self) -> WebDriverResult<WebElement<'a>> {
+        self.run_poller(Vec::new())
+    }
+
+    /// Wait until the element exists and satisfies the specified condition, and return it.
+    pub fn condition(self, f: ElementPredicate) -> WebDriverResult<WebElement<'a>> {
+        self.run_poller(vec![f])
+    }
+
+    /// Wait until the element exists and satisfies all of the specified conditions,
+    /// and return it.
+    pub fn conditions(self, conditions: Vec<ElementPredicate>) -> WebDriverResult<WebElement<'a>> {
+        self.run_poller(conditions)
+    }
+
+    /// Wait until the element exists and is displayed, and return it.
+    pub fn displayed(self) -> WebDriverResult<WebElement<'a>> {
+        let ignore_errors = self.ignore_errors;
+        self.condition(conditions::element_is_displayed(ignore_errors))
+    }
+
+    /// Wait until the element exists and is enabled, and return it.
+    pub fn enabled(self) -> WebDriverResult<WebElement<'a>> {
+        let ignore_errors = self.ignore_errors;
+        self.condition(conditions::element_is_enabled(ignore_errors))
+    }
+
+    /// Wait until the element exists and is clickable, and return it.
+    pub fn clickable(self) -> WebDriverResult<WebElement<'a>> {
+        let ignore_errors = self.ignore_errors;
+        self.condition(conditions::element_is_clickable(ignore_errors))
+    }
+}
+
+#[cfg(test)]
+/// This function checks if the public  methods implement Send. It is not intended to be executed.
+fn _test_is_send() -> WebDriverResult<()> {
+    use crate::prelude::*;
+
+    // Helper methods
+    fn is_send_val<T: Send>(_val: &T) {}
+
+    // Pre values
+    let caps = DesiredCapabilities::chrome();
+    let driver = WebDriver::new("http://localhost:4444", &caps)?;
+
+    // ElementFindWaiter
+    is_send_val(&driver.wait_for_element(By::Css("div")).exists());
+    is_send_val(&driver.wait_for_element(By::Css("div")).displayed());
+    is_send_val(&driver.wait_for_element(By::Css("div")).clickable());
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::error::{WebDriverError, WebDriverErrorInfo};
 use crate::prelude::{WebDriver, WebDriverResult};
@@ -7,7 +7,9 @@ use crate::{By, WebDriverCommands, WebDriverSession, WebElement};
 use stringmatch::Needle;
 
 use crate::query::conditions::{handle_errors, negate};
-use crate::query::{conditions, ElementPoller, ElementPollerTicker, ElementPredicate};
+use crate::query::{
+    conditions, ElementPoller, ElementPollerTicker, ElementPredicate, PollCallback,
+};
 
 /// Get String containing comma-separated list of selectors used.
 fn get_selector_summary(selectors: &[ElementSelector]) -> String {
@@ -97,6 +99,20 @@ impl<'a> ElementSelector<'a> {
     }
 }
 
+/// Information about a single poll iteration of an ElementQuery.
+/// See [ElementQuery::on_poll()](struct.ElementQuery.html#method.on_poll).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollInfo {
+    /// The number of poll iterations so far, including this one (starting at 1).
+    pub tries: u32,
+    /// The time elapsed since the query started.
+    pub elapsed: Duration,
+    /// The number of elements matched by each selector branch (after filtering) during this
+    /// iteration, in the order the branches were added. Branches after the first one that
+    /// matched are not run, and are therefore not included.
+    pub matches: Vec<usize>,
+}
+
 /// Elements can be queried from either a WebDriver or from a WebElement.
 /// The command issued to the webdriver will differ depending on the source,
 /// i.e. FindElement vs FindElementFromElement etc. but the ElementQuery
@@ -104,6 +120,7 @@ impl<'a> ElementSelector<'a> {
 pub enum ElementQuerySource<'a> {
     Driver(&'a WebDriverSession),
     Element(&'a WebElement<'a>),
+    OwnedElement(WebElement<'a>),
 }
 
 /// High-level interface for performing powerful element queries using a
@@ -132,6 +149,8 @@ pub struct ElementQuery<'a> {
     selectors: Vec<ElementSelector<'a>>,
     ignore_errors: bool,
     description: String,
+    on_poll: Option<PollCallback>,
+    retry_on_stale: bool,
 }
 
 impl<'a> ElementQuery<'a> {
@@ -139,7 +158,7 @@ impl<'a> ElementQuery<'a> {
     // Constructor
     //
 
-    fn new(source: ElementQuerySource<'a>, poller: ElementPoller, by: By<'a>) -> Self {
+    pub(crate) fn new(source: ElementQuerySource<'a>, poller: ElementPoller, by: By<'a>) -> Self {
         let selector = ElementSelector::new(by.clone());
         Self {
             source: Arc::new(source),
@@ -147,6 +166,8 @@ impl<'a> ElementQuery<'a> {
             selectors: vec![selector],
             ignore_errors: true,
             description: String::new(),
+            on_poll: None,
+            retry_on_stale: true,
         }
     }
 
@@ -165,6 +186,47 @@ impl<'a> ElementQuery<'a> {
         self
     }
 
+    /// By default, if a filter returns `WebDriverError::StaleElementReference` because the
+    /// DOM was re-rendered during the query, the current poll iteration is discarded and the
+    /// query is retried on the next iteration. If the final iteration is also discarded, the
+    /// error is returned. Set this to false to return the error immediately instead.
+    ///
+    /// Note that filters which ignore errors will simply not match a stale element.
+    pub fn retry_on_stale(mut self, retry: bool) -> Self {
+        self.retry_on_stale = retry;
+        self
+    }
+
+    /// Call the specified function after every poll iteration, with details such as the
+    /// number of tries so far and the number of elements matched by each branch.
+    ///
+    /// This is useful for debugging slow or flaky queries, or for emitting metrics.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver
+    ///     .query(By::Id("button1"))
+    ///     .on_poll(|info| println!("Poll {} after {:?}: {:?}", info.tries, info.elapsed, info.matches))
+    ///     .first()?;
+    /// #     assert_eq!(elem.tag_name()?, "button");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn on_poll<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(PollInfo) + 'static,
+    {
+        self.on_poll = Some(Box::new(callback));
+        self
+    }
+
     //
     // Poller / Waiter
     //
@@ -280,6 +342,8 @@ impl<'a> ElementQuery<'a> {
             return Err(no_such_element_error);
         }
         let mut ticker = ElementPollerTicker::new(self.poller.clone());
+        let start = Instant::now();
+        let mut tries = 0;
 
         let check = |value: bool| {
             if inverted {
@@ -290,6 +354,9 @@ impl<'a> ElementQuery<'a> {
         };
 
         loop {
+            tries += 1;
+            let mut matches = Vec::new();
+            let mut stale_error = None;
             for selector in &self.selectors {
                 let mut elements = match self.fetch_elements_from_source(selector) {
                     Ok(x) => x,
@@ -298,20 +365,51 @@ impl<'a> ElementQuery<'a> {
                 };
 
                 if !elements.is_empty() {
-                    elements = selector.run_filters(elements)?;
+                    elements = match selector.run_filters(elements) {
+                        Ok(x) => x,
+                        // The DOM changed while filtering, so discard this iteration.
+                        Err(e @ WebDriverError::StaleElementReference(_))
+                            if self.retry_on_stale =>
+                        {
+                            stale_error = Some(e);
+                            break;
+                        }
+                        Err(e) => return Err(e),
+                    };
+                }
+
+                if self.on_poll.is_some() {
+                    matches.push(elements.len());
                 }
 
                 if check(!elements.is_empty()) {
+                    self.notify_poll(tries, start, matches);
                     return Ok(elements);
                 }
             }
 
+            self.notify_poll(tries, start, matches);
             if !ticker.tick() {
-                return Ok(Vec::new());
+                // If the last iteration was discarded, its result is unknown.
+                return match stale_error {
+                    Some(e) => Err(e),
+                    None => Ok(Vec::new()),
+                };
             }
         }
     }
 
+    /// Call the on_poll callback, if any.
+    fn notify_poll(&self, tries: u32, start: Instant, matches: Vec<usize>) {
+        if let Some(callback) = &self.on_poll {
+            callback(PollInfo {
+                tries,
+                elapsed: start.elapsed(),
+                matches,
+            });
+        }
+    }
+
     /// Execute the specified selector and return any matched WebElements.
     fn fetch_elements_from_source(
         &self,
@@ -324,10 +422,14 @@ impl<'a> ElementQuery<'a> {
             true => match source.as_ref() {
                 ElementQuerySource::Driver(driver) => driver.find_element(by).map(|x| vec![x]),
                 ElementQuerySource::Element(element) => element.find_element(by).map(|x| vec![x]),
+                ElementQuerySource::OwnedElement(element) => {
+                    element.find_element(by).map(|x| vec![x])
+                }
             },
             false => match source.as_ref() {
                 ElementQuerySource::Driver(driver) => driver.find_elements(by),
                 ElementQuerySource::Element(element) => element.find_elements(by),
+                ElementQuerySource::OwnedElement(element) => element.find_elements(by),
             },
         }
     }
@@ -1,9 +1,11 @@
 use crate::error::WebDriverError;
 use crate::prelude::WebDriverResult;
 use crate::query::conditions::handle_errors;
-use crate::query::{conditions, ElementPoller, ElementPollerTicker, ElementPredicate};
+use crate::query::{
+    conditions, poller_interval, ElementPoller, ElementPollerTicker, ElementPredicate,
+};
 use crate::WebElement;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use stringmatch::Needle;
 
 /// High-level interface for performing explicit waits using the builder pattern.
@@ -70,6 +72,13 @@ impl<'a> ElementWaiter<'a> {
         self.with_poller(ElementPoller::TimeoutWithInterval(timeout, interval))
     }
 
+    /// Force this ElementWaiter to wait for the specified timeout, keeping the polling
+    /// interval of the current poller.
+    fn within(self, timeout: Duration) -> Self {
+        let interval = poller_interval(&self.poller);
+        self.wait(timeout, interval)
+    }
+
     fn run_poller(&self, conditions: Vec<ElementPredicate>) -> WebDriverResult<bool> {
         let mut ticker = ElementPollerTicker::new(self.poller.clone());
         loop {
@@ -91,6 +100,21 @@ impl<'a> ElementWaiter<'a> {
         }
     }
 
+    fn run_poller_any(&self, conditions: Vec<ElementPredicate>) -> WebDriverResult<bool> {
+        let mut ticker = ElementPollerTicker::new(self.poller.clone());
+        loop {
+            for f in &conditions {
+                if f(self.element)? {
+                    return Ok(true);
+                }
+            }
+
+            if !ticker.tick() {
+                return Ok(false);
+            }
+        }
+    }
+
     fn timeout(self) -> WebDriverResult<()> {
         Err(WebDriverError::Timeout(self.message))
     }
@@ -109,6 +133,17 @@ impl<'a> ElementWaiter<'a> {
         }
     }
 
+    /// Wait until any one of the specified conditions is met.
+    ///
+    /// This is useful when waiting for one of several possible outcomes, such as either a
+    /// success message or an error message being displayed.
+    pub fn any_condition(self, conditions: Vec<ElementPredicate>) -> WebDriverResult<()> {
+        match self.run_poller_any(conditions)? {
+            true => Ok(()),
+            false => self.timeout(),
+        }
+    }
+
     pub fn stale(self) -> WebDriverResult<()> {
         let ignore_errors = self.ignore_errors;
         self.condition(Box::new(move |elem| {
@@ -156,6 +191,96 @@ impl<'a> ElementWaiter<'a> {
         self.condition(conditions::element_is_not_clickable(ignore_errors))
     }
 
+    /// Wait up to the specified timeout for the element to be displayed.
+    ///
+    /// This is shorthand for `wait(timeout, interval).displayed()`, using the polling
+    /// interval of the current poller.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.wait_until().displayed_within(Duration::from_secs(5))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn displayed_within(self, timeout: Duration) -> WebDriverResult<()> {
+        self.within(timeout).displayed()
+    }
+
+    /// Wait up to the specified timeout for the element to be enabled.
+    ///
+    /// This is shorthand for `wait(timeout, interval).enabled()`, using the polling
+    /// interval of the current poller.
+    pub fn enabled_within(self, timeout: Duration) -> WebDriverResult<()> {
+        self.within(timeout).enabled()
+    }
+
+    /// Wait up to the specified timeout for the element to be clickable.
+    ///
+    /// This is shorthand for `wait(timeout, interval).clickable()`, using the polling
+    /// interval of the current poller.
+    pub fn clickable_within(self, timeout: Duration) -> WebDriverResult<()> {
+        self.within(timeout).clickable()
+    }
+
+    /// Wait until the element's text has remained unchanged for the specified duration,
+    /// and return the settled text.
+    ///
+    /// This is useful for counters, timers or streaming text, where you want the value to
+    /// stop changing before asserting on it, rather than waiting for a specific value.
+    /// The text is polled at the interval of the current poller, so `stability` should be
+    /// comfortably longer than the polling interval.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let text = elem.wait_until().text_settled(Duration::from_secs(1))?;
+    /// #     assert_eq!(text, "BUTTON 1");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn text_settled(self, stability: Duration) -> WebDriverResult<String> {
+        let mut ticker = ElementPollerTicker::new(self.poller.clone());
+        let mut last_text: Option<String> = None;
+        let mut last_change = Instant::now();
+        loop {
+            match self.element.text() {
+                Ok(text) => {
+                    if last_text.as_ref() != Some(&text) {
+                        last_text = Some(text);
+                        last_change = Instant::now();
+                    } else if last_change.elapsed() >= stability {
+                        return Ok(text);
+                    }
+                }
+                Err(e) if !self.ignore_errors => return Err(e),
+                Err(_) => {
+                    last_text = None;
+                }
+            }
+
+            if !ticker.tick() {
+                return Err(WebDriverError::Timeout(self.message));
+            }
+        }
+    }
+
     pub fn has_class<N>(self, class_name: N) -> WebDriverResult<()>
     where
         N: Needle + Clone + Send + Sync + 'static,
@@ -367,7 +492,13 @@ fn _test_is_send() -> WebDriverResult<()> {
     is_send_val(&elem.wait_until().displayed());
     is_send_val(&elem.wait_until().selected());
     is_send_val(&elem.wait_until().enabled());
+    is_send_val(&elem.wait_until().displayed_within(Duration::from_secs(1)));
+    is_send_val(&elem.wait_until().text_settled(Duration::from_secs(1)));
     is_send_val(&elem.wait_until().condition(Box::new(|elem| elem.is_enabled().or(Ok(false)))));
+    is_send_val(&elem.wait_until().any_condition(vec![
+        conditions::element_is_displayed(true),
+        conditions::element_is_enabled(true),
+    ]));
 
     Ok(())
 }
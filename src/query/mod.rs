@@ -134,9 +134,11 @@
 //!
 
 pub mod conditions;
+mod element_find_waiter;
 mod element_query;
 mod element_waiter;
 mod poller;
+pub use element_find_waiter::*;
 pub use element_query::*;
 pub use element_waiter::*;
 pub use poller::*;
@@ -147,3 +149,6 @@ pub use stringmatch::StringMatch;
 /// Function signature for element predicates.
 pub type ElementPredicate =
     Box<dyn Fn(&crate::webelement::WebElement) -> crate::error::WebDriverResult<bool>>;
+
+/// Function signature for the ElementQuery poll callback.
+pub type PollCallback = Box<dyn Fn(PollInfo)>;
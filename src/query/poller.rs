@@ -68,6 +68,17 @@ impl ElementPollerTicker {
     }
 }
 
+/// Get the polling interval for the specified poller. `ElementPoller::NoWait` has no
+/// interval, so the default interval of 500ms is used instead.
+pub(crate) fn poller_interval(poller: &ElementPoller) -> Duration {
+    match poller {
+        ElementPoller::NoWait => Duration::from_millis(500),
+        ElementPoller::TimeoutWithInterval(_, interval)
+        | ElementPoller::NumTriesWithInterval(_, interval)
+        | ElementPoller::TimeoutWithIntervalAndMinTries(_, interval, _) => *interval,
+    }
+}
+
 impl<T: 'static> GenericWebDriver<T>
 where
     T: WebDriverHttpClientSync,
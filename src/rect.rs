@@ -0,0 +1,75 @@
+use crate::{ElementRect, Rect};
+
+/// Helper methods for performing layout calculations with `ElementRect` and `Rect`.
+///
+/// Rectangles are treated as half-open, i.e. they include their top and left edges but
+/// not their bottom and right edges. This means two rectangles that merely touch along an
+/// edge do not intersect, and a rectangle with zero width or height contains no points.
+///
+/// # Example:
+/// ```rust
+/// use thirtyfour_sync::{ElementRect, RectExt};
+///
+/// let a = ElementRect { x: 0.0, y: 0.0, width: 10.0, height: 10.0 };
+/// let b = ElementRect { x: 5.0, y: 5.0, width: 10.0, height: 10.0 };
+/// assert_eq!(a.area(), 100.0);
+/// assert!(a.contains_point(0.0, 0.0));
+/// assert!(!a.contains_point(10.0, 5.0));
+/// assert!(a.intersects(&b));
+///
+/// // Rectangles that only touch along an edge do not intersect.
+/// let c = ElementRect { x: 10.0, y: 0.0, width: 10.0, height: 10.0 };
+/// assert!(!a.intersects(&c));
+///
+/// // Zero-size rectangles contain nothing and intersect nothing.
+/// let empty = ElementRect { x: 5.0, y: 5.0, width: 0.0, height: 0.0 };
+/// assert_eq!(empty.area(), 0.0);
+/// assert!(!empty.contains_point(5.0, 5.0));
+/// assert!(!a.intersects(&empty));
+/// ```
+pub trait RectExt {
+    /// Get the (x, y, width, height) of this rectangle.
+    fn bounds(&self) -> (f64, f64, f64, f64);
+
+    /// Get the area of this rectangle.
+    fn area(&self) -> f64 {
+        let (_, _, width, height) = self.bounds();
+        width * height
+    }
+
+    /// Get the (x, y) coordinates of the center of this rectangle.
+    fn center(&self) -> (f64, f64) {
+        let (x, y, width, height) = self.bounds();
+        (x + width / 2.0, y + height / 2.0)
+    }
+
+    /// Return true if the specified point is within this rectangle, otherwise false.
+    fn contains_point(&self, x: f64, y: f64) -> bool {
+        let (left, top, width, height) = self.bounds();
+        x >= left && x < left + width && y >= top && y < top + height
+    }
+
+    /// Return true if this rectangle overlaps the other rectangle, otherwise false.
+    fn intersects<R: RectExt>(&self, other: &R) -> bool {
+        let (x1, y1, w1, h1) = self.bounds();
+        let (x2, y2, w2, h2) = other.bounds();
+        self.area() > 0.0
+            && other.area() > 0.0
+            && x1 < x2 + w2
+            && x2 < x1 + w1
+            && y1 < y2 + h2
+            && y2 < y1 + h1
+    }
+}
+
+impl RectExt for ElementRect {
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        (self.x, self.y, self.width, self.height)
+    }
+}
+
+impl RectExt for Rect {
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        (self.x as f64, self.y as f64, self.width as f64, self.height as f64)
+    }
+}
@@ -0,0 +1,105 @@
+/// Script that returns the accessible name of each rendered element on the page whose ARIA
+/// role is `arguments[0]`, as an array of `{ name, element }` objects in document order.
+///
+/// The role is the explicit `role` attribute if present, otherwise the implicit role of the
+/// element as defined by the HTML-AAM spec (for the most commonly used elements only).
+/// The accessible name is a simplified version of the accname algorithm.
+pub(crate) const FIND_BY_ROLE_SCRIPT: &str = r#"
+let wanted = arguments[0].toLowerCase();
+const SECTIONING = "article, aside, main, nav, section";
+
+function inputRole(el) {
+    let type = (el.getAttribute("type") || "text").toLowerCase();
+    switch (type) {
+        case "button": case "submit": case "reset": case "image": return "button";
+        case "checkbox": return "checkbox";
+        case "radio": return "radio";
+        case "range": return "slider";
+        case "number": return "spinbutton";
+        case "search": return el.hasAttribute("list") ? "combobox" : "searchbox";
+        case "email": case "tel": case "text": case "url":
+            return el.hasAttribute("list") ? "combobox" : "textbox";
+        default: return null;
+    }
+}
+
+function implicitRole(el) {
+    let tag = el.tagName.toLowerCase();
+    switch (tag) {
+        case "a": case "area": return el.hasAttribute("href") ? "link" : null;
+        case "article": return "article";
+        case "aside": return "complementary";
+        case "button": case "summary": return "button";
+        case "dialog": return "dialog";
+        case "fieldset": case "details": case "optgroup": return "group";
+        case "footer": return el.closest(SECTIONING) ? null : "contentinfo";
+        case "form": return "form";
+        case "h1": case "h2": case "h3": case "h4": case "h5": case "h6": return "heading";
+        case "header": return el.closest(SECTIONING) ? null : "banner";
+        case "hr": return "separator";
+        case "img": return el.getAttribute("alt") === "" ? "presentation" : "img";
+        case "input": return inputRole(el);
+        case "li": return "listitem";
+        case "main": return "main";
+        case "nav": return "navigation";
+        case "ol": case "ul": case "menu": return "list";
+        case "option": return "option";
+        case "progress": return "progressbar";
+        case "section":
+            return el.hasAttribute("aria-label") || el.hasAttribute("aria-labelledby")
+                ? "region"
+                : null;
+        case "select": return el.multiple || el.size > 1 ? "listbox" : "combobox";
+        case "table": return "table";
+        case "td": return "cell";
+        case "textarea": return "textbox";
+        case "th": return "columnheader";
+        case "tr": return "row";
+        default: return null;
+    }
+}
+
+function role(el) {
+    let explicit = (el.getAttribute("role") || "").trim().split(/\s+/)[0];
+    return explicit ? explicit.toLowerCase() : implicitRole(el);
+}
+
+function accessibleName(el) {
+    let labelledBy = el.getAttribute("aria-labelledby");
+    if (labelledBy) {
+        let text = labelledBy.split(/\s+/)
+            .map(id => document.getElementById(id))
+            .filter(e => e)
+            .map(e => e.innerText || e.textContent)
+            .join(" ")
+            .trim();
+        if (text) return text;
+    }
+    let ariaLabel = (el.getAttribute("aria-label") || "").trim();
+    if (ariaLabel) return ariaLabel;
+    if (el.labels && el.labels.length > 0) {
+        let text = Array.from(el.labels).map(l => l.innerText).join(" ").trim();
+        if (text) return text;
+    }
+    let tag = el.tagName.toLowerCase();
+    if (tag === "input") {
+        let type = (el.getAttribute("type") || "text").toLowerCase();
+        if (["button", "submit", "reset"].includes(type)) return el.value;
+        if (type === "image") return el.getAttribute("alt") || "";
+    }
+    if (tag === "img" || tag === "area") {
+        let alt = (el.getAttribute("alt") || "").trim();
+        if (alt) return alt;
+    }
+    if (!["input", "select", "textarea", "img", "table", "ul", "ol", "form"].includes(tag)) {
+        let text = (el.innerText || "").trim();
+        if (text) return text;
+    }
+    return (el.getAttribute("title") || el.getAttribute("placeholder") || "").trim();
+}
+
+return Array.from(document.querySelectorAll("*"))
+    .filter(el => el.getClientRects().length > 0 && !el.closest("[aria-hidden='true']"))
+    .filter(el => role(el) === wanted)
+    .map(el => ({ name: accessibleName(el), element: el }));
+"#;
@@ -1,17 +1,36 @@
+use crate::alert::{DISMISS_DIALOGS_SCRIPT, RESTORE_DIALOGS_SCRIPT};
+use crate::common::command::Command;
 use crate::common::config::WebDriverConfig;
+use crate::config::WebDriverConfigExt;
 use crate::error::{WebDriverError, WebDriverResult};
-use crate::http::connection_sync::WebDriverHttpClientSync;
+use crate::extensions::chrome::ChromeDevTools;
+use crate::http::connection_sync::{request_method_name, WebDriverHttpClientSync};
+use crate::timing::{command_name, CommandTiming};
+use crate::webdrivercommands::{add_script_on_new_document, start_session};
 use crate::SessionId;
-use crate::WebDriverCommands;
+use crate::{TimeoutConfiguration, WebDriverCommands};
+use base64::decode;
+use log::{error, info, warn};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use thirtyfour::common::command::FormatRequestData;
+use thirtyfour::{RequestData, RequestMethod};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WebDriverSession {
     session_id: SessionId,
     conn: Arc<Mutex<dyn WebDriverHttpClientSync>>,
     config: WebDriverConfig,
+    deadline: Option<Instant>,
+    dismiss_dialogs_script_id: Option<String>,
+    disable_animations_on_navigate: bool,
+    command_timings: Arc<Mutex<HashMap<String, CommandTiming>>>,
+    extra_http_headers: Arc<Mutex<HashMap<String, String>>>,
+    emulated_media_type: Arc<Mutex<String>>,
 }
 
 impl WebDriverSession {
@@ -20,6 +39,12 @@ impl WebDriverSession {
             session_id,
             conn,
             config: WebDriverConfig::new(),
+            deadline: None,
+            dismiss_dialogs_script_id: None,
+            disable_animations_on_navigate: false,
+            command_timings: Arc::new(Mutex::new(HashMap::new())),
+            extra_http_headers: Arc::new(Mutex::new(HashMap::new())),
+            emulated_media_type: Arc::new(Mutex::new(String::new())),
         }
     }
 
@@ -35,12 +60,289 @@ impl WebDriverSession {
         &mut self.config
     }
 
+    /// Get the deadline after which all commands will fail, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Set the deadline after which all commands will fail immediately with
+    /// `WebDriverError::Timeout`, or `None` to remove the deadline.
+    ///
+    /// Deleting the session is still allowed after the deadline, so that the browser is
+    /// not left running. A command started before the deadline is not interrupted.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
+    /// Return true if CSS animations are disabled automatically after each navigation.
+    pub fn disable_animations_on_navigate(&self) -> bool {
+        self.disable_animations_on_navigate
+    }
+
+    /// Automatically call `disable_animations()` after every successful navigation command,
+    /// i.e. `get()`, `back()`, `forward()` and `refresh()`. If disabling the animations fails,
+    /// the error is logged and the navigation still succeeds.
+    ///
+    /// Page loads triggered by the page itself, such as clicking a link, are not detected.
+    pub fn set_disable_animations_on_navigate(&mut self, enabled: bool) {
+        self.disable_animations_on_navigate = enabled;
+    }
+
+    /// Return the timings recorded while command timing was enabled via
+    /// `WebDriverConfigExt::set_command_timing()`, keyed by the HTTP method and the path
+    /// relative to the session, with element ids and cookie names replaced by placeholders,
+    /// e.g. `GET /element/{id}/text`.
+    ///
+    /// The duration includes the full HTTP round-trip, but not any time spent waiting for
+    /// other threads using the same session. Copies of this session (e.g. via
+    /// `clone_session_handle()`) record into the same timings. Returns an empty map if
+    /// command timing has never been enabled.
+    pub fn command_timings(&self) -> HashMap<String, CommandTiming> {
+        self.command_timings.lock().map(|t| t.clone()).unwrap_or_default()
+    }
+
+    /// Discard all timings recorded so far, without disabling command timing.
+    pub fn reset_command_timings(&self) {
+        if let Ok(mut timings) = self.command_timings.lock() {
+            timings.clear();
+        }
+    }
+
+    /// The extra HTTP headers currently set via CDP, e.g. by `set_locale()` and
+    /// `set_basic_auth()`. `Network.setExtraHTTPHeaders` replaces all extra headers at once,
+    /// so these are tracked here in order to change one header without losing the others.
+    pub(crate) fn extra_http_headers(&self) -> &Mutex<HashMap<String, String>> {
+        &self.extra_http_headers
+    }
+
+    /// The CSS media type currently emulated via CDP, or `""` if none. This is tracked here
+    /// because `Emulation.setEmulatedMedia` sets the media type and the media features
+    /// together, and an arbitrary emulated media type cannot be read back from the page.
+    pub(crate) fn emulated_media_type(&self) -> &Mutex<String> {
+        &self.emulated_media_type
+    }
+
+    /// Return true if alert, confirm and prompt dialogs are being dismissed automatically.
+    pub fn is_auto_dismissing_alerts(&self) -> bool {
+        self.dismiss_dialogs_script_id.is_some()
+    }
+
+    /// Automatically dismiss all alert, confirm and prompt dialogs opened by the page,
+    /// or stop doing so.
+    ///
+    /// When enabled, `window.alert()`, `window.confirm()` and `window.prompt()` are replaced
+    /// with functions that return immediately as if the user had dismissed the dialog, both
+    /// on the current page and on every page loaded subsequently. The override is installed
+    /// via the Chrome DevTools Protocol, so this is only supported on Chromium-based browsers.
+    /// Since no dialog is ever shown, there is nothing to switch to via `switch_to().alert()`.
+    ///
+    /// Alternatively, the `unhandledPromptBehavior` capability can be set to `"dismiss"`
+    /// when starting the session, e.g. via `CapabilitiesExt::set_capability()`. That works in
+    /// all browsers and also covers dialogs opened before any script runs, but it applies for
+    /// the lifetime of the session, and the next command after a dialog is opened may still
+    /// return `WebDriverError::UnexpectedAlertOpen` depending on the driver.
+    pub fn auto_dismiss_alerts(&mut self, enabled: bool) -> WebDriverResult<()> {
+        if enabled == self.is_auto_dismissing_alerts() {
+            return Ok(());
+        }
+
+        if enabled {
+            let script_id = add_script_on_new_document(self, DISMISS_DIALOGS_SCRIPT)?;
+            self.dismiss_dialogs_script_id = Some(script_id);
+        } else if let Some(script_id) = &self.dismiss_dialogs_script_id {
+            let dev_tools = ChromeDevTools::new(self);
+            dev_tools.execute_cdp_with_params(
+                "Page.removeScriptToEvaluateOnNewDocument",
+                json!({ "identifier": script_id }),
+            )?;
+            self.execute_script(RESTORE_DIALOGS_SCRIPT)?;
+            self.dismiss_dialogs_script_id = None;
+        }
+        Ok(())
+    }
+
+    /// Save a screenshot into the specified directory after a failed command. This sends the
+    /// screenshot request directly via the HTTP client, so a failed screenshot cannot trigger
+    /// another one.
+    fn capture_error_screenshot(&self, conn: &dyn WebDriverHttpClientSync, dir: &Path) {
+        let v = match conn.execute(Command::TakeScreenshot.format_request(&self.session_id)) {
+            Ok(v) => v,
+            Err(e) => {
+                error!("Failed to take screenshot after command error: {}", e);
+                return;
+            }
+        };
+        let png = match v["value"].as_str().map(decode) {
+            Some(Ok(png)) => png,
+            _ => {
+                error!("Failed to decode screenshot after command error");
+                return;
+            }
+        };
+
+        let timestamp =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+        let path = dir.join(format!("error-{}-{}.png", self.session_id, timestamp));
+        match fs::create_dir_all(dir).and_then(|_| fs::write(&path, png)) {
+            Ok(_) => info!("Saved screenshot after command error: {}", path.display()),
+            Err(e) => error!("Failed to save screenshot to {}: {}", path.display(), e),
+        }
+    }
+
+    /// Execute the request, capturing a screenshot if it fails and this is enabled, and
+    /// recording how long it took if command timing is enabled.
+    fn execute_request(
+        &self,
+        conn: &dyn WebDriverHttpClientSync,
+        request_data: RequestData,
+    ) -> WebDriverResult<serde_json::Value> {
+        let screenshot_dir = self.config.screenshot_on_error_dir().filter(|_| {
+            !self.is_delete_session(&request_data) && !request_data.url.ends_with("/screenshot")
+        });
+        let timing_name = self.config.is_timing_commands().then(|| {
+            command_name(
+                request_method_name(&request_data.method),
+                &request_data.url,
+                &self.session_id.to_string(),
+            )
+        });
+        let started = Instant::now();
+        let result = conn.execute(request_data);
+        if let Some(name) = timing_name {
+            if let Ok(mut timings) = self.command_timings.lock() {
+                timings.entry(name).or_default().record(started.elapsed());
+            }
+        }
+        if let (Err(e), Some(dir)) = (&result, screenshot_dir) {
+            if !matches!(e, WebDriverError::NoSuchElement(_) | WebDriverError::NoSuchFrame(_)) {
+                self.capture_error_screenshot(conn, &dir);
+            }
+        }
+        result
+    }
+
+    fn is_delete_session(&self, request_data: &RequestData) -> bool {
+        matches!(request_data.method, RequestMethod::Delete)
+            && request_data.url == format!("/session/{}", self.session_id)
+    }
+
+    fn is_navigation(&self, request_data: &RequestData) -> bool {
+        let prefix = format!("/session/{}/", self.session_id);
+        matches!(request_data.method, RequestMethod::Post)
+            && request_data
+                .url
+                .strip_prefix(&prefix)
+                .map_or(false, |command| matches!(command, "url" | "back" | "forward" | "refresh"))
+    }
+
+    /// Disable animations after a successful navigation. The navigation has already
+    /// happened, so a failure here (e.g. the page opened an alert on load) is logged rather
+    /// than returned in place of the navigation result.
+    fn disable_animations_after_navigation(&self) {
+        if let Err(e) = self.disable_animations() {
+            warn!("Failed to disable animations after navigation: {}", e);
+        }
+    }
+
+    fn check_deadline(&self, request_data: &RequestData) -> WebDriverResult<()> {
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline && !self.is_delete_session(request_data) {
+                return Err(WebDriverError::Timeout(format!(
+                    "Deadline exceeded before sending request: {}",
+                    request_data.url
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub fn execute(
         &self,
         request: Box<dyn FormatRequestData + Send + Sync>,
+    ) -> WebDriverResult<serde_json::Value> {
+        let request_data = request.format_request(&self.session_id);
+        self.check_deadline(&request_data)?;
+
+        let navigated = self.disable_animations_on_navigate && self.is_navigation(&request_data);
+        let conn = self.conn.lock().map_err(|e| WebDriverError::RequestFailed(e.to_string()))?;
+        let result = self.execute_request(&*conn, request_data);
+        drop(conn);
+        if navigated && result.is_ok() {
+            self.disable_animations_after_navigation();
+        }
+        result
+    }
+
+    /// Execute the specified request using a custom HTTP request timeout for this request only.
+    ///
+    /// This is useful for commands that are known to be slow, such as uploading a large file
+    /// or loading a large page, without raising the timeout for all other requests.
+    ///
+    /// The previous timeout is restored afterwards. This requires the HTTP client to report
+    /// its timeout via `WebDriverHttpClientSync::request_timeout()`, which all of the clients
+    /// in this crate do. Otherwise `WebDriverError::RequestFailed` is returned without sending
+    /// the request, rather than leaving the custom timeout in place for all later requests.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use thirtyfour_sync::common::command::Command;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.session().execute_with_timeout(
+    ///     Box::new(Command::NavigateTo("http://webappdemo".to_string())),
+    ///     Duration::from_secs(300),
+    /// )?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn execute_with_timeout(
+        &self,
+        request: Box<dyn FormatRequestData + Send + Sync>,
+        timeout: Duration,
+    ) -> WebDriverResult<serde_json::Value> {
+        let request_data = request.format_request(&self.session_id);
+        self.check_deadline(&request_data)?;
+
+        let mut conn =
+            self.conn.lock().map_err(|e| WebDriverError::RequestFailed(e.to_string()))?;
+        let navigated = self.disable_animations_on_navigate && self.is_navigation(&request_data);
+        let previous_timeout = conn.request_timeout().ok_or_else(|| {
+            WebDriverError::RequestFailed(
+                "The HTTP client does not report its request timeout, so it cannot be restored \
+                 after execute_with_timeout()"
+                    .to_string(),
+            )
+        })?;
+        conn.set_request_timeout(timeout);
+        let result = self.execute_request(&*conn, request_data);
+        conn.set_request_timeout(previous_timeout);
+        drop(conn);
+        if navigated && result.is_ok() {
+            self.disable_animations_after_navigation();
+        }
+        result
+    }
+
+    /// Start a new session using the same HTTP client, replacing the session id of this
+    /// session. Returns the capabilities of the new session.
+    pub(crate) fn start_new_session(
+        &mut self,
+        capabilities: &serde_json::Value,
+        timeouts: TimeoutConfiguration,
     ) -> WebDriverResult<serde_json::Value> {
         let conn = self.conn.lock().map_err(|e| WebDriverError::RequestFailed(e.to_string()))?;
-        conn.execute(request.format_request(&self.session_id))
+        let (session_id, session_capabilities) = start_session(&*conn, capabilities, timeouts)?;
+        drop(conn);
+        self.session_id = session_id;
+        self.dismiss_dialogs_script_id = None;
+        self.extra_http_headers = Arc::new(Mutex::new(HashMap::new()));
+        self.emulated_media_type = Arc::new(Mutex::new(String::new()));
+        Ok(session_capabilities)
     }
 
     pub fn set_request_timeout(&mut self, timeout: Duration) -> WebDriverResult<()> {
@@ -1,10 +1,13 @@
+use std::time::Duration;
+
+use crate::query::{poller_interval, ElementPoller, ElementPollerTicker};
 use crate::webdrivercommands::WebDriverCommands;
 use crate::WebDriverSession;
 use crate::{
     common::command::Command,
     common::types::WindowHandle,
-    error::{WebDriverError, WebDriverResult},
-    {webelement::convert_element_sync, Alert, WebElement},
+    error::{WebDriverError, WebDriverErrorInfo, WebDriverResult},
+    {webelement::convert_element_sync, Alert, By, WebElement},
 };
 
 /// Struct for switching between frames/windows/alerts.
@@ -113,6 +116,9 @@ impl<'a> SwitchTo<'a> {
 
     /// Switch to the specified iframe element.
     ///
+    /// If the element is not an `<iframe>` or `<frame>` element, `WebDriverError::NoSuchFrame`
+    /// is returned without attempting to switch.
+    ///
     /// # Example:
     /// ```rust
     /// # use thirtyfour_sync::prelude::*;
@@ -133,9 +139,147 @@ impl<'a> SwitchTo<'a> {
     /// # }
     /// ```
     pub fn frame_element(self, frame_element: &WebElement) -> WebDriverResult<()> {
+        let tag_name = frame_element.tag_name()?.to_lowercase();
+        if tag_name != "iframe" && tag_name != "frame" {
+            return Err(WebDriverError::NoSuchFrame(WebDriverErrorInfo::new(&format!(
+                "Element is a <{}>, not a frame",
+                tag_name
+            ))));
+        }
+
         self.cmd(Command::SwitchToFrameElement(frame_element.element_id.clone())).map(|_| ())
     }
 
+    /// Switch to the iframe matched by the specified selector.
+    ///
+    /// This is equivalent to calling `find_element()` followed by `frame_element()`.
+    /// If no element matches, `WebDriverError::NoSuchElement` is returned. If the element is
+    /// not a frame, `WebDriverError::NoSuchFrame` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pageiframe"))?.click()?;
+    /// driver.switch_to().frame(By::Id("iframeid1"))?;
+    /// // We can now search for elements within the iframe.
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.click()?;
+    /// #     let elem_result = driver.find_element(By::Id("button-result"))?;
+    /// #     assert_eq!(elem_result.text()?, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn frame(self, by: By) -> WebDriverResult<()> {
+        let frame_element = self.session.find_element(by)?;
+        self.frame_element(&frame_element)
+    }
+
+    /// Wait up to the specified timeout for the iframe matched by the specified selector
+    /// to exist, and then switch to it.
+    ///
+    /// This is useful for iframes that are added to the page asynchronously, such as payment
+    /// widgets or embedded editors, which are often not present immediately after the page
+    /// has loaded. The frame is polled for using the interval of the query poller from the
+    /// config. If the frame is not found within the timeout, `WebDriverError::Timeout` is
+    /// returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pageiframe"))?.click()?;
+    /// let timeout = Duration::from_secs(10);
+    /// driver.switch_to().wait_for_frame_and_switch(By::Id("iframeid1"), timeout)?;
+    /// // We can now search for elements within the iframe.
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// #     elem.click()?;
+    /// #     let elem_result = driver.find_element(By::Id("button-result"))?;
+    /// #     assert_eq!(elem_result.text()?, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_frame_and_switch(self, by: By, timeout: Duration) -> WebDriverResult<()> {
+        let interval = poller_interval(&self.session.config().query_poller);
+        let mut ticker =
+            ElementPollerTicker::new(ElementPoller::TimeoutWithInterval(timeout, interval));
+        loop {
+            match self.session.switch_to().frame(by.clone()) {
+                Ok(()) => return Ok(()),
+                Err(WebDriverError::NoSuchElement(_)) | Err(WebDriverError::NoSuchFrame(_)) => {}
+                Err(e) => return Err(e),
+            }
+
+            if !ticker.tick() {
+                return Err(WebDriverError::Timeout(format!(
+                    "Timed out after {:?} waiting for frame: {}",
+                    timeout, by
+                )));
+            }
+        }
+    }
+
+    /// Switch to a nested iframe, starting from the default content.
+    ///
+    /// Each selector is used to find the next frame within the frame selected by the
+    /// previous one, so `&[By::Id("outer"), By::Id("inner")]` switches to the frame with
+    /// id `inner` inside the frame with id `outer`. An empty slice switches to the default
+    /// content.
+    ///
+    /// If a frame cannot be found, `WebDriverError::NoSuchFrame` is returned, with a message
+    /// indicating which level of the path failed. The session is then left inside the last
+    /// frame that was found.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pageiframe"))?.click()?;
+    /// #     driver.find_element(By::Id("iframeid1"))?;
+    /// driver.switch_to().frame_path(&[By::Id("iframeid1")])?;
+    /// // We can now search for elements within the iframe.
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// #     elem.click()?;
+    /// #     let elem_result = driver.find_element(By::Id("button-result"))?;
+    /// #     assert_eq!(elem_result.text()?, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn frame_path(self, selectors: &[By]) -> WebDriverResult<()> {
+        self.session.switch_to().default_content()?;
+        for (level, by) in selectors.iter().enumerate() {
+            match self.session.switch_to().frame(by.clone()) {
+                Ok(()) => {}
+                Err(WebDriverError::NoSuchElement(info))
+                | Err(WebDriverError::NoSuchFrame(info)) => {
+                    return Err(WebDriverError::NoSuchFrame(WebDriverErrorInfo::new(&format!(
+                        "Failed to switch to frame at level {} of {} in frame path, using \
+                         selector {}: {}",
+                        level + 1,
+                        selectors.len(),
+                        by,
+                        info.value.message
+                    ))));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
     /// Switch to the parent frame.
     ///
     /// # Example:
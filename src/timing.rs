@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+/// Aggregate timings for one type of WebDriver command, as returned by
+/// [WebDriver::command_timings()](struct.GenericWebDriver.html#method.command_timings).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommandTiming {
+    /// The number of times the command was executed.
+    pub count: u32,
+    /// The total time spent executing the command.
+    pub total: Duration,
+    /// The longest time taken by a single execution of the command.
+    pub max: Duration,
+}
+
+impl CommandTiming {
+    /// The mean time taken to execute the command, or zero if it was never executed.
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::default()
+        } else {
+            self.total / self.count
+        }
+    }
+
+    pub(crate) fn record(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.total += elapsed;
+        self.max = self.max.max(elapsed);
+    }
+}
+
+/// Get the name used to group timings for the specified request, e.g.
+/// `GET /element/{id}/text`. The path is made relative to the session, and element ids and
+/// cookie names are replaced with placeholders.
+pub(crate) fn command_name(method: &str, url: &str, session_id: &str) -> String {
+    let prefix = format!("/session/{}", session_id);
+    let path = match url.strip_prefix(&prefix).unwrap_or(url) {
+        "" => "/",
+        path => path,
+    };
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        let placeholder = match segments.last() {
+            Some(&"element") | Some(&"shadow") if segment != "active" => Some("{id}"),
+            Some(&"cookie") => Some("{name}"),
+            _ => None,
+        };
+        segments.push(placeholder.unwrap_or(segment));
+    }
+    format!("{} {}", method, segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::mockdriver_sync::{mock_driver, MockDriverSync};
+    use crate::prelude::*;
+
+    #[test]
+    fn command_name_uses_session_relative_path() {
+        assert_eq!(command_name("GET", "/session/abc/title", "abc"), "GET /title");
+        assert_eq!(command_name("DELETE", "/session/abc", "abc"), "DELETE /");
+        assert_eq!(command_name("POST", "/session", "abc"), "POST /session");
+    }
+
+    #[test]
+    fn command_name_replaces_ids_and_names() {
+        assert_eq!(
+            command_name("GET", "/session/abc/element/e-1/text", "abc"),
+            "GET /element/{id}/text"
+        );
+        assert_eq!(
+            command_name("POST", "/session/abc/element/e-1/element", "abc"),
+            "POST /element/{id}/element"
+        );
+        assert_eq!(
+            command_name("POST", "/session/abc/shadow/s-1/elements", "abc"),
+            "POST /shadow/{id}/elements"
+        );
+        assert_eq!(
+            command_name("GET", "/session/abc/element/active", "abc"),
+            "GET /element/active"
+        );
+        assert_eq!(command_name("GET", "/session/abc/cookie", "abc"), "GET /cookie");
+        assert_eq!(
+            command_name("DELETE", "/session/abc/cookie/token", "abc"),
+            "DELETE /cookie/{name}"
+        );
+    }
+
+    #[test]
+    fn command_timing_record_and_mean() {
+        let mut timing = CommandTiming::default();
+        assert_eq!(timing.mean(), Duration::default());
+
+        timing.record(Duration::from_millis(10));
+        timing.record(Duration::from_millis(30));
+        assert_eq!(timing.count, 2);
+        assert_eq!(timing.total, Duration::from_millis(40));
+        assert_eq!(timing.max, Duration::from_millis(30));
+        assert_eq!(timing.mean(), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn command_timing_enabled_via_config() {
+        let mock = MockDriverSync::new();
+        mock.add_response("GET", "/title", serde_json::json!("Demo Web App"));
+        let mut driver = mock_driver(&mock);
+        driver.title().unwrap();
+        assert!(driver.command_timings().is_empty());
+
+        driver.config_mut().set_command_timing(true).unwrap();
+        driver.title().unwrap();
+        driver.session.clone().title().unwrap();
+        assert_eq!(driver.command_timings()["GET /title"].count, 2);
+
+        driver.config_mut().set_command_timing(false).unwrap();
+        driver.title().unwrap();
+        assert_eq!(driver.command_timings()["GET /title"].count, 2);
+        driver.session.reset_command_timings();
+        assert!(driver.command_timings().is_empty());
+    }
+}
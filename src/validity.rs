@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+/// Javascript that returns the `validity` state of the element in `arguments[0]`.
+/// The `ValidityState` object is not serializable as-is, so each property is copied.
+pub(crate) const VALIDITY_SCRIPT: &str = r#"
+let validity = arguments[0].validity;
+if (!validity) {
+    return null;
+}
+return {
+    valid: validity.valid,
+    valueMissing: validity.valueMissing,
+    typeMismatch: validity.typeMismatch,
+    patternMismatch: validity.patternMismatch,
+    tooLong: validity.tooLong,
+    tooShort: validity.tooShort,
+    rangeUnderflow: validity.rangeUnderflow,
+    rangeOverflow: validity.rangeOverflow,
+    stepMismatch: validity.stepMismatch,
+    badInput: validity.badInput,
+    customError: validity.customError
+};
+"#;
+
+/// The HTML5 constraint validation state of a form element, as returned by
+/// [WebElement::validity()](struct.WebElement.html#method.validity).
+///
+/// Each field corresponds to the property of the same name on the element's
+/// `ValidityState` object in the browser.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidityState {
+    /// True if the element meets all of its validation constraints.
+    pub valid: bool,
+    /// True if the element is `required` but has no value.
+    pub value_missing: bool,
+    /// True if the value is not in the required syntax (e.g. for `type="email"`).
+    pub type_mismatch: bool,
+    /// True if the value does not match the `pattern` attribute.
+    pub pattern_mismatch: bool,
+    /// True if the value is longer than the `maxlength` attribute.
+    pub too_long: bool,
+    /// True if the value is shorter than the `minlength` attribute.
+    pub too_short: bool,
+    /// True if the value is less than the `min` attribute.
+    pub range_underflow: bool,
+    /// True if the value is greater than the `max` attribute.
+    pub range_overflow: bool,
+    /// True if the value does not fit the `step` attribute.
+    pub step_mismatch: bool,
+    /// True if the browser is unable to convert the user's input.
+    pub bad_input: bool,
+    /// True if a custom validity message was set via `setCustomValidity()`.
+    pub custom_error: bool,
+}
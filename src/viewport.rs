@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+/// Javascript that returns the viewport and screen metrics.
+pub(crate) const VIEWPORT_METRICS_SCRIPT: &str = r#"
+return {
+    innerWidth: window.innerWidth,
+    innerHeight: window.innerHeight,
+    devicePixelRatio: window.devicePixelRatio || 1,
+    screenWidth: window.screen.width,
+    screenHeight: window.screen.height,
+    availWidth: window.screen.availWidth,
+    availHeight: window.screen.availHeight
+};
+"#;
+
+/// The size of the viewport and screen, as returned by
+/// [WebDriver::viewport_metrics()](struct.WebDriver.html#method.viewport_metrics).
+///
+/// All sizes are in CSS pixels. Multiply by `device_pixel_ratio` to get the size in
+/// device pixels, e.g. to correlate positions in a screenshot with element positions.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewportMetrics {
+    /// The width of the viewport, including any vertical scrollbar.
+    pub inner_width: u32,
+    /// The height of the viewport, including any horizontal scrollbar.
+    pub inner_height: u32,
+    /// The ratio of device pixels to CSS pixels, e.g. 2.0 on a typical HiDPI display.
+    pub device_pixel_ratio: f64,
+    /// The width of the screen.
+    pub screen_width: u32,
+    /// The height of the screen.
+    pub screen_height: u32,
+    /// The width of the screen available to windows, excluding e.g. a vertical taskbar.
+    pub avail_width: u32,
+    /// The height of the screen available to windows, excluding e.g. a horizontal taskbar.
+    pub avail_height: u32,
+}
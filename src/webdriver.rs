@@ -1,17 +1,24 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
 
-use log::error;
+use log::{error, warn};
 use serde::Serialize;
 use serde_json::Value;
 
 use crate::common::config::WebDriverConfig;
+#[cfg(feature = "bidi")]
+use crate::error::WebDriverError;
+#[cfg(feature = "bidi")]
+use crate::http::bidi_sync::BiDiConnectionSync;
 use crate::http::connection_sync::{HttpClientCreateParams, WebDriverHttpClientSync};
 use crate::http::reqwest_sync::ReqwestDriverSync;
 use crate::webdrivercommands::{start_session, WebDriverCommands};
-use crate::{common::command::Command, error::WebDriverResult, DesiredCapabilities};
-use crate::{SessionId, WebDriverSession};
-use std::time::Duration;
+use crate::{
+    common::command::Command, error::WebDriverResult, DesiredCapabilities, TimeoutConfiguration,
+};
+use crate::{CommandTiming, SessionId, WebDriverSession};
+use std::time::{Duration, Instant};
 
 /// The WebDriver struct represents a browser session.
 ///
@@ -45,6 +52,8 @@ pub type WebDriver = GenericWebDriver<ReqwestDriverSync>;
 pub struct GenericWebDriver<T: WebDriverHttpClientSync> {
     pub session: WebDriverSession,
     capabilities: Value,
+    requested_capabilities: Value,
+    requested_timeouts: TimeoutConfiguration,
     quit_on_drop: bool,
     phantom: PhantomData<T>,
 }
@@ -104,6 +113,57 @@ where
         capabilities: C,
         timeout: Option<Duration>,
     ) -> WebDriverResult<Self>
+    where
+        C: Serialize,
+    {
+        Self::new_with_timeout_config(
+            server_url,
+            capabilities,
+            timeout,
+            TimeoutConfiguration::default(),
+        )
+    }
+
+    /// Creates a new GenericWebDriver just like the `new_with_timeout` function, but also
+    /// allows configuring the script, page load and implicit wait timeouts that are set on
+    /// the new session.
+    ///
+    /// By default, a new session has an implicit wait timeout, which means that
+    /// `find_element()` and `find_elements()` will block on the server until an element is
+    /// found or the timeout elapses. If you rely on `query()` and the query poller instead
+    /// (for example with `query().nowait()`), you will typically want to set the implicit
+    /// wait timeout to zero here.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use thirtyfour_sync::TimeoutConfiguration;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// let caps = DesiredCapabilities::chrome();
+    /// let timeouts = TimeoutConfiguration::new(
+    ///     Some(Duration::from_secs(60)),
+    ///     Some(Duration::from_secs(60)),
+    ///     Some(Duration::from_secs(0)),
+    /// );
+    /// let driver = WebDriver::new_with_timeout_config(
+    ///     "http://localhost:4444/wd/hub",
+    ///     &caps,
+    ///     Some(Duration::from_secs(120)),
+    ///     timeouts,
+    /// )?;
+    /// #     assert_eq!(driver.get_timeouts()?.implicit(), Some(Duration::from_secs(0)));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_timeout_config<C>(
+        server_url: &str,
+        capabilities: C,
+        timeout: Option<Duration>,
+        timeouts: TimeoutConfiguration,
+    ) -> WebDriverResult<Self>
     where
         C: Serialize,
     {
@@ -112,12 +172,53 @@ where
             timeout,
         };
         let conn = T::create(params)?;
+        Self::new_with_client_and_timeouts(conn, capabilities, timeouts)
+    }
 
-        let (session_id, session_capabilities) = start_session(&conn, capabilities)?;
+    /// Creates a new GenericWebDriver using an existing HTTP client, rather than creating
+    /// one from a server URL.
+    ///
+    /// This is mainly useful for testing, for example with
+    /// [MockDriverSync](http/mockdriver_sync/struct.MockDriverSync.html), which lets you
+    /// supply canned responses instead of connecting to a real WebDriver server.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// use thirtyfour_sync::http::mockdriver_sync::MockDriverSync;
+    /// use thirtyfour_sync::GenericWebDriver;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let caps = DesiredCapabilities::chrome();
+    /// let driver = GenericWebDriver::new_with_client(MockDriverSync::new(), &caps)?;
+    /// #     assert_eq!(driver.session_id().to_string(), "mock-session");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_client<C>(conn: T, capabilities: C) -> WebDriverResult<Self>
+    where
+        C: Serialize,
+    {
+        Self::new_with_client_and_timeouts(conn, capabilities, TimeoutConfiguration::default())
+    }
+
+    fn new_with_client_and_timeouts<C>(
+        conn: T,
+        capabilities: C,
+        timeouts: TimeoutConfiguration,
+    ) -> WebDriverResult<Self>
+    where
+        C: Serialize,
+    {
+        let requested_capabilities = serde_json::to_value(capabilities)?;
+        let (session_id, session_capabilities) =
+            start_session(&conn, &requested_capabilities, timeouts.clone())?;
 
         let driver = GenericWebDriver {
             session: WebDriverSession::new(session_id, Arc::new(Mutex::new(conn))),
             capabilities: session_capabilities,
+            requested_capabilities,
+            requested_timeouts: timeouts,
             quit_on_drop: true,
             phantom: PhantomData,
         };
@@ -149,6 +250,48 @@ where
         Ok(())
     }
 
+    /// End the current webdriver session and start a new one in its place, using the same
+    /// capabilities that were originally requested. This gives you a fresh browser without
+    /// having to construct a new WebDriver.
+    ///
+    /// The config, deadline and current timeouts are preserved for the new session. This
+    /// also works if the previous session is no longer usable (e.g. the browser crashed): if
+    /// the current timeouts cannot be read, the timeouts the driver was created with are used
+    /// instead, and a failure to delete the previous session is logged and otherwise ignored.
+    ///
+    /// **NOTE:** Any WebElements from the previous session will be stale and can no longer be
+    /// used. Session handles obtained via `clone_session_handle()` will also still refer to the
+    /// previous session.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// let caps = DesiredCapabilities::chrome();
+    /// let mut driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// #     let old_session_id = driver.session_id().clone();
+    /// driver.restart_session()?;
+    /// #     assert_ne!(driver.session_id(), &old_session_id);
+    /// driver.get("http://webappdemo")?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn restart_session(&mut self) -> WebDriverResult<()> {
+        let timeouts = self.get_timeouts().unwrap_or_else(|e| {
+            warn!("Failed to get timeouts before restarting session, using the originals: {}", e);
+            self.requested_timeouts.clone()
+        });
+        if let Err(e) = self.cmd(Command::DeleteSession) {
+            warn!("Failed to delete session before restarting it: {}", e);
+        }
+        self.capabilities =
+            self.session.start_new_session(&self.requested_capabilities, timeouts)?;
+        Ok(())
+    }
+
     /// Set the request timeout for the HTTP client.
     ///
     /// # Example
@@ -167,6 +310,174 @@ where
     pub fn set_request_timeout(&mut self, timeout: Duration) -> WebDriverResult<()> {
         self.session.set_request_timeout(timeout)
     }
+
+    /// Get a cheaply-clonable handle to this WebDriver's session, which can be sent to other
+    /// threads and implements `WebDriverCommands`.
+    ///
+    /// **NOTE:** A WebDriver session controls a single browser, and all commands are
+    /// serialized by a mutex around the HTTP client, so using multiple threads will not make
+    /// individual commands run in parallel. It can still be convenient for read-heavy scraping,
+    /// or for checking on the browser from a background thread.
+    ///
+    /// The handle takes a copy of the current config and deadline, so later changes to either
+    /// are not shared. The handle does not keep the browser open: once the WebDriver is quit or
+    /// dropped, any commands sent via the handle will fail.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// let caps = DesiredCapabilities::chrome();
+    /// let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// let handle = driver.clone_session_handle();
+    /// let title = std::thread::spawn(move || handle.title()).join().unwrap()?;
+    /// assert_eq!(title, "Demo Web App");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn clone_session_handle(&self) -> WebDriverSession {
+        self.session.clone()
+    }
+
+    /// Set a deadline after which all commands will fail immediately with
+    /// `WebDriverError::Timeout`, instead of being sent to the WebDriver server.
+    ///
+    /// This gives a hard wall-clock limit across many commands, e.g. for a whole test.
+    /// A command started before the deadline is not interrupted, but is still subject to the
+    /// request timeout. Deleting the session (via `quit()` or on drop) is always allowed,
+    /// so that the browser is not left running.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::{Duration, Instant};
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// let caps = DesiredCapabilities::chrome();
+    /// let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?
+    ///     .with_deadline(Instant::now() + Duration::from_secs(60));
+    /// driver.get("http://webappdemo")?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.set_deadline(Some(deadline));
+        self
+    }
+
+    /// Set or remove the deadline after which all commands will fail.
+    /// See `with_deadline()` for more details.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.session.set_deadline(deadline);
+    }
+
+    /// Automatically dismiss all alert, confirm and prompt dialogs opened by the page,
+    /// or stop doing so. This is only supported on Chromium-based browsers.
+    ///
+    /// See [WebDriverSession::auto_dismiss_alerts()](struct.WebDriverSession.html#method.auto_dismiss_alerts)
+    /// for more details, including the alternative of using the `unhandledPromptBehavior`
+    /// capability.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// let mut driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.auto_dismiss_alerts(true)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.find_element(By::Id("pagealerts"))?.click()?;
+    /// driver.find_element(By::Id("alertbutton1"))?.click()?;
+    /// // No alert was shown.
+    /// assert!(driver.switch_to().alert().text().is_err());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn auto_dismiss_alerts(&mut self, enabled: bool) -> WebDriverResult<()> {
+        self.session.auto_dismiss_alerts(enabled)
+    }
+
+    /// Automatically disable CSS animations after every successful call to `get()`,
+    /// `back()`, `forward()` or `refresh()`.
+    ///
+    /// See [WebDriver::disable_animations()](struct.WebDriver.html#method.disable_animations)
+    /// for more details.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// let mut driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.set_disable_animations_on_navigate(true);
+    /// // Animations are disabled once the page has loaded.
+    /// driver.get("http://webappdemo")?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_disable_animations_on_navigate(&mut self, enabled: bool) {
+        self.session.set_disable_animations_on_navigate(enabled);
+    }
+
+    /// Return the aggregate timings for each type of command, recorded while command timing
+    /// was enabled via `WebDriverConfigExt::set_command_timing()`. This is useful for finding
+    /// out which commands dominate the run time of a slow test suite.
+    ///
+    /// See [WebDriverSession::command_timings()](struct.WebDriverSession.html#method.command_timings)
+    /// for details of how commands are grouped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// let mut driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.config_mut().set_command_timing(true)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.find_element(By::Id("button1"))?.click()?;
+    /// let mut timings: Vec<_> = driver.command_timings().into_iter().collect();
+    /// timings.sort_by(|a, b| b.1.total.cmp(&a.1.total));
+    /// for (command, timing) in timings {
+    ///     println!("{}: {} calls, {:?} total", command, timing.count, timing.total);
+    /// }
+    /// #     assert_eq!(driver.command_timings()["POST /url"].count, 1);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn command_timings(&self) -> HashMap<String, CommandTiming> {
+        self.session.command_timings()
+    }
+
+    /// Open a WebDriver BiDi connection for this session, using the `webSocketUrl`
+    /// capability returned by the WebDriver server.
+    ///
+    /// The session must have been created with the `webSocketUrl` capability set to `true`,
+    /// otherwise `WebDriverError::NotFound` is returned. Requires the `bidi` cargo feature.
+    ///
+    /// See [BiDiConnectionSync](http/bidi_sync/struct.BiDiConnectionSync.html) for more
+    /// details and an example.
+    #[cfg(feature = "bidi")]
+    pub fn bidi_connect(&self) -> WebDriverResult<BiDiConnectionSync> {
+        let url = self.capabilities["webSocketUrl"].as_str().ok_or_else(|| {
+            WebDriverError::NotFound(
+                "webSocketUrl".to_string(),
+                "The session does not support WebDriver BiDi. Set the webSocketUrl \
+                 capability to true when creating the session"
+                    .to_string(),
+            )
+        })?;
+        BiDiConnectionSync::connect(url)
+    }
 }
 
 impl<T> WebDriverCommands for GenericWebDriver<T>
@@ -1,11 +1,23 @@
-use std::{fs::File, io::Write, path::Path, time::Duration};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fs::File,
+    io::Write,
+    path::Path,
+    thread::sleep,
+    time::{Duration, Instant},
+};
 
-use base64::decode;
+use base64::{decode, encode};
+use log::error;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
+use serde_json::{from_value, json, Value};
+use stringmatch::Needle;
 
-use crate::error::WebDriverError;
+use crate::accessibility::AccessibilityNode;
+use crate::error::{no_such_element, WebDriverError, WebDriverErrorInfo};
+use crate::extensions::chrome::ChromeDevTools;
 use crate::http::connection_sync::WebDriverHttpClientSync;
 use crate::WebDriverSession;
 use crate::{
@@ -14,24 +26,59 @@ use crate::{
         command::Command,
         command::ExtensionCommand,
         connection_common::{convert_json, convert_json_vec},
+        keys::Keys,
     },
     error::WebDriverResult,
+    js_errors::{JsError, COLLECT_ERRORS_SCRIPT, INSTALL_ERROR_COLLECTOR_SCRIPT},
+    links::{link_status, Link, LinkOptions, ALL_LINKS_SCRIPT},
+    network::{mock_script, unmock_script, MockResponse, NetworkInterceptor},
+    performance::{PerformanceTiming, RawPerformanceTiming, PERFORMANCE_TIMING_SCRIPT},
+    query::{
+        poller_interval, ElementFindWaiter, ElementPoller, ElementPollerTicker, ElementQuery,
+        ElementQuerySource,
+    },
+    roles::FIND_BY_ROLE_SCRIPT,
+    viewport::{ViewportMetrics, VIEWPORT_METRICS_SCRIPT},
     webelement::{convert_element_sync, convert_elements_sync},
     By, Cookie, OptionRect, Rect, ScriptArgs, SessionId, SwitchTo, TimeoutConfiguration,
     WebElement, WindowHandle,
 };
 use thirtyfour::common::command::FormatRequestData;
+use thirtyfour::{RequestData, RequestMethod};
+
+/// Javascript that injects a stylesheet disabling all CSS transitions and animations.
+const DISABLE_ANIMATIONS_SCRIPT: &str = r#"
+if (!document.getElementById("thirtyfour-disable-animations")) {
+    let style = document.createElement("style");
+    style.id = "thirtyfour-disable-animations";
+    style.textContent = "*, *::before, *::after { " +
+        "transition: none !important; animation: none !important; }";
+    (document.head || document.documentElement).appendChild(style);
+}
+"#;
 
 pub fn start_session<C>(
     conn: &dyn WebDriverHttpClientSync,
     capabilities: C,
+    timeouts: TimeoutConfiguration,
 ) -> WebDriverResult<(SessionId, serde_json::Value)>
 where
     C: Serialize,
 {
     let caps = serde_json::to_value(capabilities)?;
-    let v = match conn.execute(Command::NewSession(caps.clone()).format_request(&SessionId::null()))
-    {
+    // Capabilities that already use the W3C structure (e.g. FirstMatchCapabilities) are sent
+    // as-is. Otherwise they are wrapped by the NewSession command.
+    let is_w3c = caps.get("alwaysMatch").is_some() || caps.get("firstMatch").is_some();
+    let new_session_request = || {
+        if is_w3c {
+            RequestData::new(RequestMethod::Post, "/session")
+                .add_body(json!({ "capabilities": caps }))
+        } else {
+            Command::NewSession(caps.clone()).format_request(&SessionId::null())
+        }
+    };
+
+    let v = match conn.execute(new_session_request()) {
         Ok(x) => Ok(x),
         Err(e) => {
             // Selenium sometimes gives a bogus 500 error "Chrome failed to start".
@@ -39,7 +86,7 @@ where
             // will be returned.
             if let WebDriverError::UnknownError(x) = &e {
                 if x.status == 500 {
-                    conn.execute(Command::NewSession(caps).format_request(&SessionId::null()))
+                    conn.execute(new_session_request())
                 } else {
                     Err(e)
                 }
@@ -71,14 +118,210 @@ where
     } else {
         resp.session_id
     });
-    // Set default timeouts.
-    conn.execute(
-        Command::SetTimeouts(TimeoutConfiguration::default()).format_request(&session_id),
-    )?;
+    // Set initial timeouts.
+    conn.execute(Command::SetTimeouts(timeouts).format_request(&session_id))?;
 
     Ok((session_id, data.capabilities))
 }
 
+/// Run the specified script with `storage` set to either `window.localStorage` or
+/// `window.sessionStorage`. Any additional arguments start at `arguments[1]`.
+fn run_storage_script<C>(
+    driver: &C,
+    storage: &str,
+    script: &str,
+    extra_args: &[&str],
+) -> WebDriverResult<Value>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    let mut args = ScriptArgs::new();
+    args.push(storage)?;
+    for arg in extra_args {
+        args.push(arg)?;
+    }
+
+    let script = format!("let storage = window[arguments[0]];\n{}", script);
+    match driver.execute_script_with_args(&script, &args) {
+        Ok(ret) => Ok(ret.value().clone()),
+        Err(e) if e.to_string().contains("SecurityError") => {
+            Err(WebDriverError::JavascriptError(WebDriverErrorInfo::new(&format!(
+                "{} is not accessible from the current page. Storage is not available for \
+                 file:// URLs, sandboxed iframes, or opaque origins such as about:blank",
+                storage
+            ))))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Emulate the specified CSS media type (or `""` for none) and `prefers-color-scheme` value
+/// (or `""` for the system default) via CDP, and record the media type on the session.
+/// `Emulation.setEmulatedMedia` replaces both the media type and all features at once.
+fn set_emulated_media<C>(driver: &C, media: &str, scheme: &str) -> WebDriverResult<()>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    let mut media_type = driver
+        .session()
+        .emulated_media_type()
+        .lock()
+        .map_err(|e| WebDriverError::RequestFailed(e.to_string()))?;
+    let dev_tools = ChromeDevTools::new(driver.session());
+    dev_tools.execute_cdp_with_params(
+        "Emulation.setEmulatedMedia",
+        json!({
+            "media": media,
+            "features": [{ "name": "prefers-color-scheme", "value": scheme }]
+        }),
+    )?;
+    *media_type = media.to_string();
+    Ok(())
+}
+
+/// Emulate the specified `prefers-color-scheme` value (or `""` for the system default) via
+/// CDP, keeping the currently emulated media type.
+fn set_emulated_color_scheme<C>(driver: &C, scheme: &str) -> WebDriverResult<()>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    let media = driver
+        .session()
+        .emulated_media_type()
+        .lock()
+        .map_err(|e| WebDriverError::RequestFailed(e.to_string()))?
+        .clone();
+    set_emulated_media(driver, &media, scheme)
+}
+
+/// Emulate the specified CSS media type (or `""` for none) via CDP, keeping the current
+/// `prefers-color-scheme`, which is read back from the page.
+fn set_emulated_media_type<C>(driver: &C, media: &str) -> WebDriverResult<()>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    let ret = driver
+        .execute_script(r#"return window.matchMedia("(prefers-color-scheme: dark)").matches;"#)?;
+    let scheme = if ret.convert::<bool>()? {
+        "dark"
+    } else {
+        "light"
+    };
+    set_emulated_media(driver, media, scheme)
+}
+
+/// Register the specified script to run on every new document via CDP, and run it on the
+/// current page too. Returns the identifier of the registered script.
+///
+/// If the script fails on the current page, the registration is removed again so that it
+/// does not leak into subsequent page loads.
+pub(crate) fn add_script_on_new_document<C>(driver: &C, source: &str) -> WebDriverResult<String>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    let dev_tools = ChromeDevTools::new(driver.session());
+    let ret = dev_tools.execute_cdp_with_params(
+        "Page.addScriptToEvaluateOnNewDocument",
+        json!({ "source": source }),
+    )?;
+    let script_id = match ret["identifier"].as_str() {
+        Some(script_id) => script_id.to_string(),
+        None => {
+            return Err(WebDriverError::RequestFailed(
+                "Page.addScriptToEvaluateOnNewDocument did not return a script identifier"
+                    .to_string(),
+            ))
+        }
+    };
+
+    if let Err(e) = driver.execute_script(source) {
+        if let Err(remove_err) = dev_tools.execute_cdp_with_params(
+            "Page.removeScriptToEvaluateOnNewDocument",
+            json!({ "identifier": script_id }),
+        ) {
+            error!("Failed to remove script after it failed on the current page: {}", remove_err);
+        }
+        return Err(e);
+    }
+    Ok(script_id)
+}
+
+/// Set the specified extra HTTP header via CDP, or remove it if `value` is `None`, keeping
+/// all other extra headers previously set via this crate.
+fn set_extra_http_header<C>(driver: &C, name: &str, value: Option<String>) -> WebDriverResult<()>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    let mut headers = driver
+        .session()
+        .extra_http_headers()
+        .lock()
+        .map_err(|e| WebDriverError::RequestFailed(e.to_string()))?;
+    let mut updated = headers.clone();
+    match value {
+        Some(value) => updated.insert(name.to_string(), value),
+        None => updated.remove(name),
+    };
+
+    let dev_tools = ChromeDevTools::new(driver.session());
+    dev_tools.execute_cdp("Network.enable")?;
+    dev_tools
+        .execute_cdp_with_params("Network.setExtraHTTPHeaders", json!({ "headers": updated }))?;
+    *headers = updated;
+    Ok(())
+}
+
+/// Return the CDP frame id of the content frame of the frame element in the top-level
+/// document that matches the specified CSS selector.
+fn cdp_frame_id(dev_tools: &ChromeDevTools, selector: &str) -> WebDriverResult<String> {
+    let document = dev_tools.execute_cdp_with_params("DOM.getDocument", json!({ "depth": 0 }))?;
+    let ret = dev_tools.execute_cdp_with_params(
+        "DOM.querySelector",
+        json!({ "nodeId": document["root"]["nodeId"], "selector": selector }),
+    )?;
+    if ret["nodeId"].as_u64().unwrap_or(0) == 0 {
+        return Err(no_such_element(
+            "Could not locate the frame element via the DevTools Protocol",
+        ));
+    }
+    let node = dev_tools
+        .execute_cdp_with_params("DOM.describeNode", json!({ "nodeId": ret["nodeId"] }))?;
+    node["node"]["frameId"].as_str().map(|x| x.to_string()).ok_or_else(|| {
+        no_such_element("Could not locate the frame element via the DevTools Protocol")
+    })
+}
+
+/// Create an isolated world for the content frame of the specified frame element via CDP,
+/// and return its execution context id.
+///
+/// CDP cannot look up WebDriver element references, so the frame element is found by
+/// temporarily marking it with an attribute and querying the top-level document for it.
+fn frame_execution_context<C>(driver: &C, frame: &WebElement) -> WebDriverResult<u64>
+where
+    C: WebDriverCommands + ?Sized,
+{
+    const MARKER: &str = "data-thirtyfour-frame";
+    let mut args = ScriptArgs::new();
+    args.push(frame)?;
+    args.push(MARKER)?;
+    driver.execute_script_with_args(r#"arguments[0].setAttribute(arguments[1], "");"#, &args)?;
+
+    let dev_tools = ChromeDevTools::new(driver.session());
+    let frame_id = cdp_frame_id(&dev_tools, &format!("[{}]", MARKER));
+    driver.execute_script_with_args(r#"arguments[0].removeAttribute(arguments[1]);"#, &args)?;
+
+    // NOTE: "grantUniveralAccess" is the parameter name used by the protocol.
+    let ret = dev_tools.execute_cdp_with_params(
+        "Page.createIsolatedWorld",
+        json!({ "frameId": frame_id?, "worldName": "thirtyfour", "grantUniveralAccess": true }),
+    )?;
+    ret["executionContextId"].as_u64().ok_or_else(|| {
+        WebDriverError::NoSuchFrame(WebDriverErrorInfo::new(
+            "Could not create an execution context for the frame",
+        ))
+    })
+}
+
 /// All browser-level W3C WebDriver commands are implemented under this trait.
 ///
 /// `Thirtyfour` is structured as follows:
@@ -248,6 +491,268 @@ pub trait WebDriverCommands {
         convert_element_sync(self.session(), &v["value"])
     }
 
+    /// Search for an element on the current page using each of the specified selectors in turn,
+    /// returning the first element found.
+    ///
+    /// This is useful for supporting multiple versions or themes of an app where the DOM
+    /// structure differs. Each selector is tried only once (subject to the implicit wait
+    /// timeout). For polling and filtering, see `WebDriver::query()` instead.
+    ///
+    /// If no selector matches an element, `WebDriverError::NoSuchElement` is returned listing
+    /// all selectors that were tried.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.set_implicit_wait_timeout(std::time::Duration::new(0, 0))?;
+    /// let elem = driver.find_element_any(&[By::Id("notfound"), By::Id("button1")])?;
+    /// #     assert_eq!(elem.id()?, Some("button1".to_string()));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn find_element_any(&self, selectors: &[By]) -> WebDriverResult<WebElement> {
+        for by in selectors {
+            match self.find_element(by.clone()) {
+                Ok(elem) => return Ok(elem),
+                Err(WebDriverError::NoSuchElement(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let criteria: Vec<String> = selectors.iter().map(|by| by.to_string()).collect();
+        Err(no_such_element(&format!(
+            "Element not found using any of the selectors: [{}]",
+            criteria.join(",")
+        )))
+    }
+
+    /// Find the form control associated with the `<label>` whose text matches the specified
+    /// needle, similar to `getByLabelText` in testing-library.
+    ///
+    /// The control is the one the browser associates with the label, i.e. the element
+    /// referenced by the label's `for` attribute, or otherwise the first labelable element
+    /// nested inside the label. The label text is trimmed before matching. Use a `&str` for
+    /// an exact match, or a `StringMatch` from the `stringmatch` crate for partial or
+    /// case-insensitive matching.
+    ///
+    /// Returns `WebDriverError::NoSuchElement` if no label matches, or if none of the
+    /// matching labels has an associated control.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// use stringmatch::StringMatch;
+    ///
+    /// // <label for="email">Email address</label><input id="email">
+    /// let elem = driver.find_by_label("Email address")?;
+    /// elem.send_keys("me@example.com")?;
+    /// // <label>Remember me <input type="checkbox"></label>
+    /// let elem = driver.find_by_label(StringMatch::new("remember").partial().case_insensitive())?;
+    /// elem.click()?;
+    /// ```
+    fn find_by_label<N>(&self, label_text: N) -> WebDriverResult<WebElement>
+    where
+        N: Needle,
+    {
+        let ret = self.execute_script(
+            r#"
+            return Array.from(document.querySelectorAll("label")).map(label => ({
+                text: label.innerText.trim(),
+                control: label.control
+            }));
+            "#,
+        )?;
+        let labels = ret.value().as_array().map(|labels| labels.as_slice()).unwrap_or_default();
+        let mut label_found = false;
+        for label in labels {
+            let text = label["text"].as_str().unwrap_or_default();
+            if !label_text.is_match(text) {
+                continue;
+            }
+            label_found = true;
+            if !label["control"].is_null() {
+                return convert_element_sync(self.session(), &label["control"]);
+            }
+        }
+
+        Err(no_such_element(if label_found {
+            "Found a matching label, but it has no associated form control"
+        } else {
+            "No label found with matching text"
+        }))
+    }
+
+    /// Find the first element whose `placeholder` attribute matches the specified needle,
+    /// similar to `getByPlaceholderText` in testing-library.
+    ///
+    /// Use a `&str` for an exact match, or a `StringMatch` from the `stringmatch` crate for
+    /// partial or case-insensitive matching. Returns `WebDriverError::NoSuchElement` if no
+    /// element matches.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// // <input type="search" placeholder="Search products">
+    /// let elem = driver.find_by_placeholder("Search products")?;
+    /// elem.send_keys("shoes")?;
+    /// ```
+    fn find_by_placeholder<N>(&self, text: N) -> WebDriverResult<WebElement>
+    where
+        N: Needle,
+    {
+        let ret = self.execute_script(
+            r#"
+            return Array.from(document.querySelectorAll("[placeholder]")).map(elem => ({
+                placeholder: elem.getAttribute("placeholder"),
+                element: elem
+            }));
+            "#,
+        )?;
+        let elems = ret.value().as_array().map(|elems| elems.as_slice()).unwrap_or_default();
+        for elem in elems {
+            if text.is_match(elem["placeholder"].as_str().unwrap_or_default()) {
+                return convert_element_sync(self.session(), &elem["element"]);
+            }
+        }
+        Err(no_such_element("No element found with matching placeholder"))
+    }
+
+    /// Find the first element with the specified ARIA role, whose accessible name matches
+    /// the specified needle, similar to `getByRole` in testing-library.
+    ///
+    /// The role is the explicit `role` attribute if present, otherwise the implicit role
+    /// of the element, e.g. `button` for `<button>` and `<input type="submit">`, `link`
+    /// for `<a href>`, `textbox` for text inputs and `heading` for `<h1>` to `<h6>`. The
+    /// role and accessible name are computed in Javascript using a simplified version of
+    /// the ARIA algorithms, which covers the most commonly used elements. Elements that are
+    /// not rendered, or are inside an `aria-hidden` element, are ignored.
+    ///
+    /// The accessible name is taken from `aria-labelledby`, `aria-label`, associated
+    /// `<label>` elements, `alt` text, the text content, or the `title` or `placeholder`
+    /// attributes, in that order. Use a `&str` for an exact match, or a `StringMatch` from
+    /// the `stringmatch` crate for partial or case-insensitive matching.
+    ///
+    /// Returns `WebDriverError::NoSuchElement` if no element matches.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("button1"))?;
+    /// let elem = driver.find_by_role("button", "BUTTON 1")?;
+    /// #     assert_eq!(elem.id()?, Some("button1".to_string()));
+    /// elem.click()?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn find_by_role<N>(&self, role: &str, name: N) -> WebDriverResult<WebElement>
+    where
+        N: Needle,
+    {
+        let mut args = ScriptArgs::new();
+        args.push(role)?;
+        let ret = self.execute_script_with_args(FIND_BY_ROLE_SCRIPT, &args)?;
+        let elems = ret.value().as_array().map(|elems| elems.as_slice()).unwrap_or_default();
+        for elem in elems {
+            if name.is_match(elem["name"].as_str().unwrap_or_default()) {
+                return convert_element_sync(self.session(), &elem["element"]);
+            }
+        }
+        Err(no_such_element(&format!(
+            "No element found with role '{}' and a matching accessible name",
+            role
+        )))
+    }
+
+    /// Search for an element on the current page, polling on the client side using the
+    /// specified `ElementPoller` until the element is found.
+    ///
+    /// This is a simpler alternative to `WebDriver::query()` for when you just want a single
+    /// find with explicit polling. Each attempt is still subject to the implicit wait timeout
+    /// on the server, so you will typically want to set that to zero when using this method.
+    ///
+    /// If the element is not found before the poller gives up, `WebDriverError::Timeout`
+    /// is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use thirtyfour_sync::query::ElementPoller;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let poller =
+    ///     ElementPoller::TimeoutWithInterval(Duration::from_secs(10), Duration::from_millis(250));
+    /// let elem = driver.find_element_with_poller(By::Id("button1"), poller)?;
+    /// #     assert_eq!(elem.tag_name()?, "button");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn find_element_with_poller(
+        &self,
+        by: By,
+        poller: ElementPoller,
+    ) -> WebDriverResult<WebElement> {
+        let mut ticker = ElementPollerTicker::new(poller);
+        loop {
+            match self.find_element(by.clone()) {
+                Ok(elem) => return Ok(elem),
+                Err(WebDriverError::NoSuchElement(_)) => {}
+                Err(e) => return Err(e),
+            }
+
+            if !ticker.tick() {
+                return Err(WebDriverError::Timeout(format!(
+                    "Timed out waiting for element using selector: {}",
+                    by
+                )));
+            }
+        }
+    }
+
+    /// Return an ElementFindWaiter that polls until the element matching the specified
+    /// selector exists and satisfies the chosen condition, and then returns the element.
+    ///
+    /// This uses the query poller from the config (see `WebDriver::set_query_poller()`)
+    /// unless overridden. See [ElementFindWaiter](query/struct.ElementFindWaiter.html) for
+    /// more documentation.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver
+    ///     .wait_for_element(By::Id("button1"))
+    ///     .within(Duration::from_secs(5))
+    ///     .displayed()?;
+    /// #     assert_eq!(elem.tag_name()?, "button");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_for_element<'a>(&'a self, by: By<'a>) -> ElementFindWaiter<'a> {
+        let poller = self.session().config().query_poller.clone();
+        ElementFindWaiter::new(self.session(), by, poller)
+    }
+
     /// Search for all elements on the current page that match the specified
     /// selector.
     ///
@@ -272,8 +777,150 @@ pub trait WebDriverCommands {
         convert_elements_sync(self.session(), &v["value"])
     }
 
+    /// Return all `<iframe>` and `<frame>` elements in the current browsing context, in
+    /// document order. Frames nested inside other frames are not included.
+    ///
+    /// Any of the returned elements can be passed to `switch_to().frame_element()`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pageiframe"))?.click()?;
+    /// #     driver.find_element(By::Id("iframeid1"))?;
+    /// let frames = driver.frames()?;
+    /// #     assert_eq!(frames[0].id()?, Some("iframeid1".to_string()));
+    /// #     assert_eq!(driver.frame_count()?, frames.len());
+    /// driver.switch_to().frame_element(&frames[0])?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn frames(&self) -> WebDriverResult<Vec<WebElement>> {
+        self.find_elements(By::Css("iframe, frame"))
+    }
+
+    /// Return the number of `<iframe>` and `<frame>` elements in the current browsing
+    /// context. See `frames()` for more details.
+    fn frame_count(&self) -> WebDriverResult<usize> {
+        Ok(self.frames()?.len())
+    }
+
+    /// Search for all elements on the current page that match the specified selector, and map
+    /// each element through the specified extractor function, returning the results.
+    ///
+    /// This is useful for scraping, where you typically find all rows in a table or list and
+    /// then extract some fields from each one. If the extractor returns an error for any
+    /// element, that error is returned immediately.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let sections = driver.find_and_extract(By::ClassName("section"), |elem| {
+    ///     elem.get_attribute("data-section")
+    /// })?;
+    /// #     assert!(!sections.is_empty());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn find_and_extract<T, F>(&self, by: By, mut extractor: F) -> WebDriverResult<Vec<T>>
+    where
+        F: FnMut(&WebElement) -> WebDriverResult<T>,
+    {
+        let elems = self.find_elements(by)?;
+        elems.iter().map(&mut extractor).collect()
+    }
+
+    /// Get the topmost element at the specified viewport coordinates, using
+    /// `document.elementFromPoint()` in Javascript.
+    ///
+    /// This is useful for diagnosing `ElementClickIntercepted` errors, since it returns the
+    /// element that would actually receive a click at that point (such as an overlay).
+    ///
+    /// If there is no element at the specified coordinates (for example because they are
+    /// outside the viewport), `WebDriverError::NoSuchElement` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let (x, y) = elem.rect()?.center();
+    /// let elem_at_point = driver.element_from_point(x, y)?;
+    /// #     assert_eq!(elem_at_point.id()?, Some("button1".to_string()));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn element_from_point(&self, x: f64, y: f64) -> WebDriverResult<WebElement> {
+        let mut args = ScriptArgs::new();
+        args.push(x)?;
+        args.push(y)?;
+        let ret = self.execute_script_with_args(
+            r#"return document.elementFromPoint(arguments[0], arguments[1]);"#,
+            &args,
+        )?;
+        if ret.value().is_null() {
+            return Err(no_such_element(&format!("No element found at point ({}, {})", x, y)));
+        }
+        ret.get_element()
+    }
+
+    /// Get all elements at the specified viewport coordinates, using
+    /// `document.elementsFromPoint()` in Javascript.
+    ///
+    /// The elements are ordered from the topmost element to the bottommost (typically ending
+    /// with the `<body>` and `<html>` elements). If there are no elements at the specified
+    /// coordinates, an empty Vec is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let (x, y) = elem.rect()?.center();
+    /// let elems = driver.elements_from_point(x, y)?;
+    /// #     assert_eq!(elems[0].id()?, Some("button1".to_string()));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn elements_from_point(&self, x: f64, y: f64) -> WebDriverResult<Vec<WebElement>> {
+        let mut args = ScriptArgs::new();
+        args.push(x)?;
+        args.push(y)?;
+        let ret = self.execute_script_with_args(
+            r#"return document.elementsFromPoint(arguments[0], arguments[1]);"#,
+            &args,
+        )?;
+        ret.get_elements()
+    }
+
     /// Execute the specified Javascript synchronously and return the result.
     ///
+    /// If the script throws an exception, this returns `WebDriverError::JavascriptError`.
+    /// The message and the Javascript stack trace (if the WebDriver server provides one) are
+    /// available via the `value` field of the error info, which lets you distinguish script
+    /// errors from transport errors such as `WebDriverError::RequestFailed`.
+    ///
     /// # Example:
     /// ```rust
     /// # use thirtyfour_sync::prelude::*;
@@ -298,6 +945,27 @@ pub trait WebDriverCommands {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// Handling an exception thrown by the script:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// use thirtyfour_sync::error::WebDriverError;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// match driver.execute_script(r#"throw new Error("something went wrong");"#) {
+    ///     Err(WebDriverError::JavascriptError(info)) => {
+    ///         assert!(info.value.message.contains("something went wrong"));
+    ///         println!("JS stack: {}", info.value.stacktrace.unwrap_or_default());
+    ///     }
+    ///     other => panic!("expected JavascriptError, got {:?}", other.map(|_| ())),
+    /// }
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
     fn execute_script(&self, script: &str) -> WebDriverResult<ScriptRetSync> {
         let v = self.cmd(Command::ExecuteScript(script.to_owned(), Vec::new()))?;
         Ok(ScriptRetSync::new(self.session(), v["value"].clone()))
@@ -414,7 +1082,25 @@ pub trait WebDriverCommands {
         Ok(ScriptRetSync::new(self.session(), v["value"].clone()))
     }
 
-    /// Get the current window handle.
+    /// Execute the specified Javascript synchronously inside the specified `<iframe>` or
+    /// `<frame>` element, without switching the session to that frame.
+    ///
+    /// On Chromium-based browsers, this uses the Chrome DevTools Protocol to evaluate the
+    /// script in an isolated world created for the frame. The isolated world shares the DOM
+    /// of the frame, but not the Javascript globals defined by the page. The frame must be
+    /// in the top-level document, and must be same-site (cross-site frames run in a separate
+    /// process which this session cannot reach via CDP).
+    ///
+    /// If any of those requirements are not met, or CDP is not supported, this falls back to
+    /// switching to the frame, running the script via `execute_script_with_args()` and then
+    /// switching back to the parent frame. The frame element must therefore be in the
+    /// current browsing context. Any other error, such as a stale frame element, is returned
+    /// without falling back. The parent frame is restored even if the script fails.
+    ///
+    /// The arguments are available via `arguments`, as for `execute_script_with_args()`.
+    /// Elements cannot be passed as arguments or returned, since element references are
+    /// only valid in the browsing context where they were found. If the script throws an
+    /// exception, `WebDriverError::JavascriptError` is returned.
     ///
     /// # Example:
     /// ```rust
@@ -424,7 +1110,81 @@ pub trait WebDriverCommands {
     /// #     let caps = DesiredCapabilities::chrome();
     /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
     /// #     driver.get("http://webappdemo")?;
-    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
+    /// #     driver.find_element(By::Id("pageiframe"))?.click()?;
+    /// let frame = driver.find_element(By::Id("iframeid1"))?;
+    /// let mut args = ScriptArgs::new();
+    /// args.push("button1")?;
+    /// let ret = driver.execute_script_in_frame(
+    ///     &frame,
+    ///     r#"return document.getElementById(arguments[0]).innerText;"#,
+    ///     &args,
+    /// )?;
+    /// assert_eq!(ret.convert::<String>()?, "BUTTON 1");
+    /// // The session is still in the top-level document.
+    /// #     driver.find_element(By::Id("iframeid1"))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn execute_script_in_frame(
+        &self,
+        frame: &WebElement,
+        script: &str,
+        args: &ScriptArgs,
+    ) -> WebDriverResult<ScriptRetSync> {
+        let context_id = match frame_execution_context(self, frame) {
+            Ok(context_id) => context_id,
+            Err(WebDriverError::UnknownCommand(_))
+            | Err(WebDriverError::UnknownMethod(_))
+            | Err(WebDriverError::NoSuchElement(_))
+            | Err(WebDriverError::NoSuchFrame(_)) => {
+                self.switch_to().frame_element(frame)?;
+                let result = self.execute_script_with_args(script, args);
+                // Always switch back, even if the script failed.
+                let switched_back = self.switch_to().parent_frame();
+                return match (result, switched_back) {
+                    (Err(e), _) | (Ok(_), Err(e)) => Err(e),
+                    (Ok(ret), Ok(_)) => Ok(ret),
+                };
+            }
+            Err(e) => return Err(e),
+        };
+
+        let expression = format!(
+            "(function() {{\n{}\n}}).apply(null, {})",
+            script,
+            Value::Array(args.get_args())
+        );
+        let dev_tools = ChromeDevTools::new(self.session());
+        let ret = dev_tools.execute_cdp_with_params(
+            "Runtime.evaluate",
+            json!({
+                "expression": expression,
+                "contextId": context_id,
+                "returnByValue": true
+            }),
+        )?;
+        if let Some(details) = ret.get("exceptionDetails") {
+            let message = details["exception"]["description"]
+                .as_str()
+                .or_else(|| details["text"].as_str())
+                .unwrap_or("Unknown error");
+            return Err(WebDriverError::JavascriptError(WebDriverErrorInfo::new(message)));
+        }
+        Ok(ScriptRetSync::new(self.session(), ret["result"]["value"].clone()))
+    }
+
+    /// Get the current window handle.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
     /// #     assert_eq!(driver.title()?, "Demo Web App");
     /// // Get the current window handle.
     /// let handle = driver.current_window_handle()?;
@@ -477,6 +1237,48 @@ pub trait WebDriverCommands {
         Ok(strings.iter().map(WindowHandle::from).collect())
     }
 
+    /// Get the type of the window with the specified handle, i.e. whether it is a normal tab
+    /// or a popup opened by another window.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Target.getTargets`, where a window that
+    /// was opened by another window (e.g. via `window.open()`) is reported as a popup.
+    /// On browsers that do not support this, or if the handle is not found,
+    /// `WindowHandleType::Unknown` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use thirtyfour_sync::WindowHandleType;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let handle = driver.current_window_handle()?;
+    /// assert_eq!(driver.window_handle_type(&handle)?, WindowHandleType::Tab);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn window_handle_type(&self, handle: &WindowHandle) -> WebDriverResult<WindowHandleType> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        let ret = match dev_tools.execute_cdp("Target.getTargets") {
+            Ok(v) => v,
+            Err(WebDriverError::UnknownCommand(_)) => return Ok(WindowHandleType::Unknown),
+            Err(e) => return Err(e),
+        };
+
+        let handle = handle.to_string();
+        let target = ret["targetInfos"].as_array().and_then(|targets| {
+            targets.iter().find(|t| t["targetId"].as_str() == Some(handle.as_str()))
+        });
+        Ok(match target {
+            Some(t) if t["type"].as_str() != Some("page") => WindowHandleType::Unknown,
+            Some(t) if t["openerId"].is_string() => WindowHandleType::Popup,
+            Some(_) => WindowHandleType::Tab,
+            None => WindowHandleType::Unknown,
+        })
+    }
+
     /// Maximize the current window.
     ///
     /// # Example:
@@ -601,6 +1403,49 @@ pub trait WebDriverCommands {
         self.cmd(Command::SetWindowRect(rect)).map(|_| ())
     }
 
+    /// Get the size of the current window, in pixels, as `(width, height)`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.set_window_size(1280, 720)?;
+    /// let (width, height) = driver.window_size()?;
+    /// #     assert_eq!((width, height), (1280, 720));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn window_size(&self) -> WebDriverResult<(u32, u32)> {
+        let rect = self.get_window_rect()?;
+        Ok((rect.width.max(0) as u32, rect.height.max(0) as u32))
+    }
+
+    /// Get the position of the current window, in pixels, as `(x, y)`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// let (x, y) = driver.window_position()?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn window_position(&self) -> WebDriverResult<(i32, i32)> {
+        let rect = self.get_window_rect()?;
+        Ok((rect.x, rect.y))
+    }
+
+    /// Set the size of the current window, in pixels. The window position is not changed.
+    fn set_window_size(&self, width: u32, height: u32) -> WebDriverResult<()> {
+        self.set_window_rect(OptionRect::new().with_size(width as i32, height as i32))
+    }
+
     /// Go back. This is equivalent to clicking the browser's back button.
     ///
     /// # Example:
@@ -902,6 +1747,55 @@ pub trait WebDriverCommands {
         convert_json::<Cookie>(&v["value"])
     }
 
+    /// Get the value of the specified cookie, or `None` if the cookie does not exist.
+    ///
+    /// Unlike `get_cookie()`, a missing cookie is not treated as an error.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     let set_cookie = Cookie::new("key", serde_json::json!("value"));
+    /// #     driver.add_cookie(set_cookie)?;
+    /// let value = driver.get_cookie_value("key")?;
+    /// assert_eq!(value, Some(serde_json::json!("value")));
+    /// assert_eq!(driver.get_cookie_value("missing")?, None);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn get_cookie_value(&self, name: &str) -> WebDriverResult<Option<Value>> {
+        match self.get_cookie(name) {
+            Ok(cookie) => Ok(Some(cookie.value().clone())),
+            Err(WebDriverError::NoSuchCookie(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Return true if the specified cookie exists, otherwise false.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     let set_cookie = Cookie::new("key", serde_json::json!("value"));
+    /// #     driver.add_cookie(set_cookie)?;
+    /// assert!(driver.has_cookie("key")?);
+    /// assert!(!driver.has_cookie("missing")?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn has_cookie(&self, name: &str) -> WebDriverResult<bool> {
+        Ok(self.get_cookie_value(name)?.is_some())
+    }
+
     /// Delete the specified cookie.
     ///
     /// # Example:
@@ -968,6 +1862,164 @@ pub trait WebDriverCommands {
         self.cmd(Command::AddCookie(cookie)).map(|_| ())
     }
 
+    /// Clear all browser data, in order to start from a clean state.
+    ///
+    /// This does the following:
+    /// - Clears `localStorage` and `sessionStorage` for the current page via Javascript.
+    /// - Deletes all cookies for the current page.
+    /// - On Chromium-based browsers only, clears the browser cache and all cookies (for all
+    ///   domains) via the Chrome DevTools Protocol commands `Network.clearBrowserCache` and
+    ///   `Network.clearBrowserCookies`. On other browsers this step is skipped.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     let set_cookie = Cookie::new("key", serde_json::json!("value"));
+    /// #     driver.add_cookie(set_cookie)?;
+    /// driver.clear_browser_data()?;
+    /// #     assert!(driver.get_cookies()?.is_empty());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn clear_browser_data(&self) -> WebDriverResult<()> {
+        // Storage access throws a SecurityError on some origins (e.g. about:blank).
+        self.execute_script(
+            r#"
+            try { window.localStorage.clear(); } catch (e) {}
+            try { window.sessionStorage.clear(); } catch (e) {}
+            "#,
+        )?;
+        self.delete_all_cookies()?;
+
+        let dev_tools = ChromeDevTools::new(self.session());
+        for cmd in &["Network.clearBrowserCache", "Network.clearBrowserCookies"] {
+            match dev_tools.execute_cdp(cmd) {
+                Ok(_) | Err(WebDriverError::UnknownCommand(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the value of the specified key in `localStorage`, or `None` if the key does not exist.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.local_storage_set("key", "value")?;
+    /// let value = driver.local_storage_get("key")?;
+    /// assert_eq!(value, Some("value".to_string()));
+    /// #     driver.local_storage_remove("key")?;
+    /// #     assert_eq!(driver.local_storage_get("key")?, None);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn local_storage_get(&self, key: &str) -> WebDriverResult<Option<String>> {
+        let v = run_storage_script(
+            self,
+            "localStorage",
+            "return storage.getItem(arguments[1]);",
+            &[key],
+        )?;
+        convert_json(&v)
+    }
+
+    /// Set the value of the specified key in `localStorage`.
+    fn local_storage_set(&self, key: &str, value: &str) -> WebDriverResult<()> {
+        run_storage_script(
+            self,
+            "localStorage",
+            "storage.setItem(arguments[1], arguments[2]);",
+            &[key, value],
+        )?;
+        Ok(())
+    }
+
+    /// Remove the specified key from `localStorage`.
+    fn local_storage_remove(&self, key: &str) -> WebDriverResult<()> {
+        run_storage_script(self, "localStorage", "storage.removeItem(arguments[1]);", &[key])?;
+        Ok(())
+    }
+
+    /// Remove all keys from `localStorage`.
+    fn local_storage_clear(&self) -> WebDriverResult<()> {
+        run_storage_script(self, "localStorage", "storage.clear();", &[])?;
+        Ok(())
+    }
+
+    /// Get all keys in `localStorage`.
+    fn local_storage_keys(&self) -> WebDriverResult<Vec<String>> {
+        let v = run_storage_script(self, "localStorage", "return Object.keys(storage);", &[])?;
+        convert_json_vec(&v)
+    }
+
+    /// Get the value of the specified key in `sessionStorage`, or `None` if the key does not exist.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.session_storage_set("key", "value")?;
+    /// let value = driver.session_storage_get("key")?;
+    /// assert_eq!(value, Some("value".to_string()));
+    /// #     driver.session_storage_remove("key")?;
+    /// #     assert_eq!(driver.session_storage_get("key")?, None);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn session_storage_get(&self, key: &str) -> WebDriverResult<Option<String>> {
+        let v = run_storage_script(
+            self,
+            "sessionStorage",
+            "return storage.getItem(arguments[1]);",
+            &[key],
+        )?;
+        convert_json(&v)
+    }
+
+    /// Set the value of the specified key in `sessionStorage`.
+    fn session_storage_set(&self, key: &str, value: &str) -> WebDriverResult<()> {
+        run_storage_script(
+            self,
+            "sessionStorage",
+            "storage.setItem(arguments[1], arguments[2]);",
+            &[key, value],
+        )?;
+        Ok(())
+    }
+
+    /// Remove the specified key from `sessionStorage`.
+    fn session_storage_remove(&self, key: &str) -> WebDriverResult<()> {
+        run_storage_script(self, "sessionStorage", "storage.removeItem(arguments[1]);", &[key])?;
+        Ok(())
+    }
+
+    /// Remove all keys from `sessionStorage`.
+    fn session_storage_clear(&self) -> WebDriverResult<()> {
+        run_storage_script(self, "sessionStorage", "storage.clear();", &[])?;
+        Ok(())
+    }
+
+    /// Get all keys in `sessionStorage`.
+    fn session_storage_keys(&self) -> WebDriverResult<Vec<String>> {
+        let v = run_storage_script(self, "sessionStorage", "return Object.keys(storage);", &[])?;
+        convert_json_vec(&v)
+    }
+
     /// Take a screenshot of the current window and return it as a
     /// base64-encoded String.
     fn screenshot_as_base64(&self) -> WebDriverResult<String> {
@@ -1032,6 +2084,99 @@ pub trait WebDriverCommands {
         Ok(())
     }
 
+    /// Get the browser's User-Agent string, as reported by `navigator.userAgent`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let user_agent = driver.user_agent()?;
+    /// #     assert!(user_agent.contains("Chrome"));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn user_agent(&self) -> WebDriverResult<String> {
+        let ret = self.execute_script(r#"return navigator.userAgent;"#)?;
+        ret.convert()
+    }
+
+    /// Get the User-Agent Client Hints data, as reported by `navigator.userAgentData`.
+    ///
+    /// The returned JSON object contains the `brands`, `mobile` and `platform` fields.
+    /// Returns `None` if the browser does not support User-Agent Client Hints.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// if let Some(data) = driver.user_agent_data()? {
+    ///     println!("Platform: {}", data["platform"]);
+    /// }
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn user_agent_data(&self) -> WebDriverResult<Option<Value>> {
+        let ret = self.execute_script(
+            r#"return navigator.userAgentData ? navigator.userAgentData.toJSON() : null;"#,
+        )?;
+        match ret.value() {
+            Value::Null => Ok(None),
+            v => Ok(Some(v.clone())),
+        }
+    }
+
+    /// Override the User-Agent string reported by the browser, both in `navigator.userAgent`
+    /// and in the `User-Agent` header of subsequent requests.
+    ///
+    /// Optionally the `Accept-Language` header and `navigator.platform` can be overridden
+    /// at the same time.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Network.setUserAgentOverride` and is
+    /// therefore only supported on Chromium-based browsers.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.set_user_agent_override("MyUserAgent/1.0", Some("en-GB"), None)?;
+    /// driver.get("http://webappdemo")?;
+    /// assert_eq!(driver.user_agent()?, "MyUserAgent/1.0");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_user_agent_override(
+        &self,
+        user_agent: &str,
+        accept_language: Option<&str>,
+        platform: Option<&str>,
+    ) -> WebDriverResult<()> {
+        let mut params = json!({ "userAgent": user_agent });
+        if let Some(accept_language) = accept_language {
+            params["acceptLanguage"] = json!(accept_language);
+        }
+        if let Some(platform) = platform {
+            params["platform"] = json!(platform);
+        }
+
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params("Network.setUserAgentOverride", params)?;
+        Ok(())
+    }
+
     /// Running an extension command.
     /// Extension commands are browser specific commands and using browser specific endpoints and
     /// parameters.
@@ -1138,31 +2283,1302 @@ pub trait WebDriverCommands {
 
         result
     }
-}
-
-/// Helper struct for getting return values from scripts.
-/// See the examples for [WebDriver::execute_script()](struct.WebDriver.html#method.execute_script)
-/// and [WebDriver::execute_async_script()](struct.WebDriver.html#method.execute_async_script).
-pub struct ScriptRetSync<'a> {
-    driver: &'a WebDriverSession,
-    value: Value,
-}
-
-impl<'a> ScriptRetSync<'a> {
-    /// Create a new ScriptRetSync. This is typically done automatically via
-    /// [WebDriver::execute_script()](struct.WebDriver.html#method.execute_script)
-    /// or [WebDriver::execute_async_script()](struct.WebDriver.html#method.execute_async_script)
-    pub fn new(driver: &'a WebDriverSession, value: Value) -> Self {
-        ScriptRetSync {
-            driver,
-            value,
-        }
-    }
 
-    /// Get the raw JSON value.
-    pub fn value(&self) -> &Value {
-        &self.value
-    }
+    /// Wait until the specified closure returns `Some(value)`, and then return that value.
+    ///
+    /// The closure is polled repeatedly, using the interval of the configured query poller
+    /// (see `WebDriver::set_query_poller()`), until either it returns `Some(value)` or the
+    /// timeout elapses. If the timeout elapses, `WebDriverError::Timeout` is returned.
+    /// Any error returned by the closure will be returned immediately.
+    ///
+    /// This can be used to wait for any arbitrary page state, such as a change of URL or title,
+    /// or the result of some custom Javascript.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let title = driver.wait_until(
+    ///     |driver| {
+    ///         let title = driver.title()?;
+    ///         Ok(if title.is_empty() { None } else { Some(title) })
+    ///     },
+    ///     Duration::from_secs(10),
+    /// )?;
+    /// #     assert_eq!(title, "Demo Web App");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_until<F, T>(&self, f: F, timeout: Duration) -> WebDriverResult<T>
+    where
+        Self: Sized,
+        F: Fn(&Self) -> WebDriverResult<Option<T>>,
+    {
+        let interval = poller_interval(&self.session().config().query_poller);
+        let mut ticker =
+            ElementPollerTicker::new(ElementPoller::TimeoutWithInterval(timeout, interval));
+        loop {
+            if let Some(value) = f(self)? {
+                return Ok(value);
+            }
+
+            if !ticker.tick() {
+                return Err(WebDriverError::Timeout(format!(
+                    "Timed out after {:?} waiting for condition",
+                    timeout
+                )));
+            }
+        }
+    }
+
+    /// Wait until the specified predicate returns true for every one of the specified
+    /// elements, e.g. to wait for all rows of a table to finish loading.
+    ///
+    /// Each time the elements are polled, the predicate is called for every element. The
+    /// interval is that of the configured query poller (see `WebDriver::set_query_poller()`).
+    /// If the timeout elapses, `WebDriverError::Timeout` is returned, listing the elements
+    /// (and their indices) that did not satisfy the predicate on the last poll. Any error
+    /// returned by the predicate will be returned immediately.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let buttons = driver.find_elements(By::Tag("button"))?;
+    /// driver.wait_until_all(&buttons, |elem| elem.is_displayed(), Duration::from_secs(10))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_until_all<F>(
+        &self,
+        elements: &[WebElement],
+        predicate: F,
+        timeout: Duration,
+    ) -> WebDriverResult<()>
+    where
+        F: Fn(&WebElement) -> WebDriverResult<bool>,
+    {
+        let interval = poller_interval(&self.session().config().query_poller);
+        let mut ticker =
+            ElementPollerTicker::new(ElementPoller::TimeoutWithInterval(timeout, interval));
+        loop {
+            let mut failed = Vec::new();
+            for (index, elem) in elements.iter().enumerate() {
+                if !predicate(elem)? {
+                    failed.push(format!("{}: {}", index, elem));
+                }
+            }
+
+            if failed.is_empty() {
+                return Ok(());
+            }
+
+            if !ticker.tick() {
+                return Err(WebDriverError::Timeout(format!(
+                    "Timed out after {:?} waiting for all elements. {} of {} elements did not \
+                     satisfy the condition: [{}]",
+                    timeout,
+                    failed.len(),
+                    elements.len(),
+                    failed.join(", ")
+                )));
+            }
+        }
+    }
+
+    /// Wait until the current URL contains the specified substring.
+    ///
+    /// On timeout, the returned `WebDriverError::Timeout` includes the last URL seen.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.wait_until_url_contains("webappdemo", Duration::from_secs(10))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_until_url_contains(&self, substring: &str, timeout: Duration) -> WebDriverResult<()>
+    where
+        Self: Sized,
+    {
+        let last_url = RefCell::new(String::new());
+        self.wait_until(
+            |driver| {
+                let url = driver.current_url()?;
+                let found = url.contains(substring);
+                *last_url.borrow_mut() = url;
+                Ok(if found {
+                    Some(())
+                } else {
+                    None
+                })
+            },
+            timeout,
+        )
+        .map_err(|e| match e {
+            WebDriverError::Timeout(_) => WebDriverError::Timeout(format!(
+                "Timed out after {:?} waiting for URL to contain '{}'. Last URL was '{}'",
+                timeout,
+                substring,
+                last_url.borrow()
+            )),
+            e => e,
+        })
+    }
+
+    /// Wait until the current URL is exactly equal to the specified URL.
+    ///
+    /// On timeout, the returned `WebDriverError::Timeout` includes the last URL seen.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.wait_until_url_is("http://webappdemo/", Duration::from_secs(10))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_until_url_is(&self, url: &str, timeout: Duration) -> WebDriverResult<()>
+    where
+        Self: Sized,
+    {
+        let last_url = RefCell::new(String::new());
+        self.wait_until(
+            |driver| {
+                let current_url = driver.current_url()?;
+                let found = current_url == url;
+                *last_url.borrow_mut() = current_url;
+                Ok(if found {
+                    Some(())
+                } else {
+                    None
+                })
+            },
+            timeout,
+        )
+        .map_err(|e| match e {
+            WebDriverError::Timeout(_) => WebDriverError::Timeout(format!(
+                "Timed out after {:?} waiting for URL to be '{}'. Last URL was '{}'",
+                timeout,
+                url,
+                last_url.borrow()
+            )),
+            e => e,
+        })
+    }
+
+    /// Wait until the page title is exactly equal to the specified title.
+    ///
+    /// On timeout, the returned `WebDriverError::Timeout` includes the last title seen.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.wait_until_title_is("Demo Web App", Duration::from_secs(10))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_until_title_is(&self, title: &str, timeout: Duration) -> WebDriverResult<()>
+    where
+        Self: Sized,
+    {
+        let last_title = RefCell::new(String::new());
+        self.wait_until(
+            |driver| {
+                let current_title = driver.title()?;
+                let found = current_title == title;
+                *last_title.borrow_mut() = current_title;
+                Ok(if found {
+                    Some(())
+                } else {
+                    None
+                })
+            },
+            timeout,
+        )
+        .map_err(|e| match e {
+            WebDriverError::Timeout(_) => WebDriverError::Timeout(format!(
+                "Timed out after {:?} waiting for title to be '{}'. Last title was '{}'",
+                timeout,
+                title,
+                last_title.borrow()
+            )),
+            e => e,
+        })
+    }
+
+    /// Wait until there has been no network activity for the specified `idle_time`.
+    ///
+    /// This is useful for single-page apps, where XHR and fetch requests typically continue
+    /// long after `document.readyState` is `complete`.
+    ///
+    /// The Chrome DevTools Protocol does report network events, but the WebDriver endpoint used
+    /// for CDP commands cannot subscribe to them. Instead, on the first poll this installs a
+    /// small Javascript hook that counts in-flight `fetch()` and `XMLHttpRequest` calls, and
+    /// then polls (using the interval of the configured query poller) both that count and the
+    /// number of entries from `performance.getEntriesByType("resource")`. The network is
+    /// considered idle once there are no in-flight requests and no new resource entries
+    /// have appeared for `idle_time`.
+    ///
+    /// **NOTE:** Requests that were already in flight before the hook was installed are not
+    /// counted until they complete, and requests made via other mechanisms (such as images,
+    /// scripts or websockets) are only detected once they have finished loading.
+    ///
+    /// If the network does not become idle before the timeout elapses,
+    /// `WebDriverError::Timeout` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.wait_for_network_idle(Duration::from_millis(500), Duration::from_secs(10))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wait_for_network_idle(&self, idle_time: Duration, timeout: Duration) -> WebDriverResult<()>
+    where
+        Self: Sized,
+    {
+        let last_activity = RefCell::new((None, Instant::now()));
+        let last_pending = RefCell::new(0u64);
+        self.wait_until(
+            |driver| {
+                let ret = driver.execute_script(
+                    r#"
+                    if (!window.__thirtyfourNetwork) {
+                        let state = { pending: 0 };
+                        window.__thirtyfourNetwork = state;
+                        if (window.fetch) {
+                            let originalFetch = window.fetch;
+                            window.fetch = function() {
+                                state.pending++;
+                                return originalFetch.apply(this, arguments).finally(() => {
+                                    state.pending--;
+                                });
+                            };
+                        }
+                        let originalSend = XMLHttpRequest.prototype.send;
+                        XMLHttpRequest.prototype.send = function() {
+                            state.pending++;
+                            this.addEventListener("loadend", () => state.pending--, { once: true });
+                            return originalSend.apply(this, arguments);
+                        };
+                    }
+                    return [
+                        window.__thirtyfourNetwork.pending,
+                        performance.getEntriesByType("resource").length
+                    ];
+                    "#,
+                )?;
+                let (pending, resources): (u64, u64) = ret.convert()?;
+                *last_pending.borrow_mut() = pending;
+
+                let mut last_activity = last_activity.borrow_mut();
+                if pending > 0 || last_activity.0 != Some(resources) {
+                    *last_activity = (Some(resources), Instant::now());
+                }
+                Ok(if pending == 0 && last_activity.1.elapsed() >= idle_time {
+                    Some(())
+                } else {
+                    None
+                })
+            },
+            timeout,
+        )
+        .map_err(|e| match e {
+            WebDriverError::Timeout(_) => WebDriverError::Timeout(format!(
+                "Timed out after {:?} waiting for network to be idle for {:?}. \
+                 Last seen {} request(s) in flight",
+                timeout,
+                idle_time,
+                last_pending.borrow()
+            )),
+            e => e,
+        })
+    }
+
+    /// Get the page load timings for the current page, such as DNS lookup time, time to first
+    /// byte, and the time taken for the `DOMContentLoaded` and `load` events.
+    ///
+    /// The timings are read from the Navigation Timing Level 2 API
+    /// (`performance.getEntriesByType("navigation")`) where supported, falling back to the
+    /// legacy `performance.timing` API otherwise. Both are normalised to durations measured
+    /// from the start of navigation.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// let timing = driver.performance_timing()?;
+    /// assert!(timing.time_to_first_byte < Duration::from_secs(5));
+    /// #     assert!(timing.load.is_some());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn performance_timing(&self) -> WebDriverResult<PerformanceTiming> {
+        let ret = self.execute_script(PERFORMANCE_TIMING_SCRIPT)?;
+        let raw: RawPerformanceTiming = ret.convert()?;
+        Ok(raw.into())
+    }
+
+    /// Get the HTTP status code of the response that loaded the current page, e.g. to check
+    /// whether `get()` loaded an error page such as a 404.
+    ///
+    /// WebDriver itself does not expose the status code, and the CDP endpoint used by this
+    /// crate cannot receive network events, so this reads the `responseStatus` of the
+    /// Navigation Timing entry for the current document. This is supported by Chromium-based
+    /// browsers (version 109 onwards) and recent versions of Firefox. On other browsers,
+    /// or if the status is not known (e.g. for `about:blank`), this returns `None`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// if let Some(status) = driver.last_response_status()? {
+    ///     assert_eq!(status, 200);
+    /// }
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn last_response_status(&self) -> WebDriverResult<Option<u16>> {
+        let ret = self.execute_script(
+            r#"
+            let entries = performance.getEntriesByType
+                ? performance.getEntriesByType("navigation")
+                : [];
+            let status = entries.length > 0 ? entries[0].responseStatus : undefined;
+            return status > 0 ? status : null;
+            "#,
+        )?;
+        ret.convert()
+    }
+
+    /// Get the size of the viewport and screen, and the device pixel ratio, in a single
+    /// request.
+    ///
+    /// The device pixel ratio is needed to correlate screenshot pixels with CSS pixels, e.g.
+    /// for responsive design and HiDPI screenshot tests.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let metrics = driver.viewport_metrics()?;
+    /// println!("Viewport: {}x{} @ {}x", metrics.inner_width, metrics.inner_height,
+    ///     metrics.device_pixel_ratio);
+    /// #     assert!(metrics.device_pixel_ratio > 0.0);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn viewport_metrics(&self) -> WebDriverResult<ViewportMetrics> {
+        let ret = self.execute_script(VIEWPORT_METRICS_SCRIPT)?;
+        ret.convert()
+    }
+
+    /// Emulate a viewport of the specified size (in CSS pixels), device pixel ratio and
+    /// mobile mode, regardless of the size of the browser window.
+    ///
+    /// Unlike `set_window_rect()`, this is not limited by the size of the screen or by the
+    /// window manager, so it can be used for pixel-accurate responsive design tests. Setting
+    /// `mobile` to true also enables mobile behaviour such as the meta viewport tag and
+    /// overlay scrollbars. The override stays in place until `clear_device_metrics()` is
+    /// called, including across navigations.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Emulation.setDeviceMetricsOverride`,
+    /// and is therefore only supported on Chromium-based browsers. Screenshots are taken at
+    /// the emulated size and device pixel ratio, so a `device_scale_factor` of 2.0 produces
+    /// screenshots with twice the width and height in pixels.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.set_device_metrics(375, 667, 2.0, true)?;
+    /// let metrics = driver.viewport_metrics()?;
+    /// assert_eq!(metrics.device_pixel_ratio, 2.0);
+    /// driver.clear_device_metrics()?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_device_metrics(
+        &self,
+        width: u32,
+        height: u32,
+        device_scale_factor: f64,
+        mobile: bool,
+    ) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params(
+            "Emulation.setDeviceMetricsOverride",
+            json!({
+                "width": width,
+                "height": height,
+                "deviceScaleFactor": device_scale_factor,
+                "mobile": mobile
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// Remove the device metrics override set by `set_device_metrics()`.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    fn clear_device_metrics(&self) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp("Emulation.clearDeviceMetricsOverride")?;
+        Ok(())
+    }
+
+    /// Emulate the specified `prefers-color-scheme` media feature, e.g. to test dark mode
+    /// styling without changing the operating system settings or launching the browser
+    /// with special flags.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Emulation.setEmulatedMedia`, and is
+    /// therefore only supported on Chromium-based browsers. The emulation stays in place
+    /// across navigations until `reset_color_scheme()` is called. Any media type set via
+    /// `set_media_type()` is kept.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// use thirtyfour_sync::ColorScheme;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.set_color_scheme(ColorScheme::Dark)?;
+    /// let ret = driver.execute_script(
+    ///     r#"return window.matchMedia("(prefers-color-scheme: dark)").matches;"#
+    /// )?;
+    /// assert!(ret.convert::<bool>()?);
+    /// driver.reset_color_scheme()?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_color_scheme(&self, scheme: ColorScheme) -> WebDriverResult<()> {
+        set_emulated_color_scheme(self, scheme.as_str())
+    }
+
+    /// Remove the color scheme emulation set by `set_color_scheme()`, so that the system
+    /// default is used again.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    fn reset_color_scheme(&self) -> WebDriverResult<()> {
+        set_emulated_color_scheme(self, "")
+    }
+
+    /// Emulate the specified CSS media type, e.g. `"print"` to test print stylesheets
+    /// without actually printing the page, or `"screen"`.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Emulation.setEmulatedMedia`, and is
+    /// therefore only supported on Chromium-based browsers. The emulation stays in place
+    /// across navigations until `reset_media_type()` is called. The current color scheme,
+    /// including any set via `set_color_scheme()`, is kept.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.set_media_type("print")?;
+    /// let ret = driver.execute_script(r#"return window.matchMedia("print").matches;"#)?;
+    /// assert!(ret.convert::<bool>()?);
+    /// // Take a screenshot of the page as it would be printed.
+    /// let png = driver.screenshot_as_png()?;
+    /// driver.reset_media_type()?;
+    /// #     assert!(!png.is_empty());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_media_type(&self, media: &str) -> WebDriverResult<()> {
+        set_emulated_media_type(self, media)
+    }
+
+    /// Remove the media type emulation set by `set_media_type()`, keeping the current color
+    /// scheme.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    fn reset_media_type(&self) -> WebDriverResult<()> {
+        set_emulated_media_type(self, "")
+    }
+
+    /// Read the text currently on the clipboard, e.g. to check the result of a
+    /// "copy to clipboard" button.
+    ///
+    /// This uses `navigator.clipboard.readText()`, which requires the clipboard permission.
+    /// The permission is granted first via the Chrome DevTools Protocol command
+    /// `Browser.grantPermissions`, so this is only supported on Chromium-based browsers.
+    /// The Clipboard API is only available on secure origins (`https` or `localhost`), and
+    /// the page must have focus. If the browser rejects the request, this returns
+    /// `WebDriverError::JavascriptError`.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// driver.find_element(By::Id("copy-link"))?.click()?;
+    /// assert_eq!(driver.read_clipboard()?, "https://example.com/share/123");
+    /// ```
+    fn read_clipboard(&self) -> WebDriverResult<String> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params(
+            "Browser.grantPermissions",
+            json!({ "permissions": ["clipboardReadWrite", "clipboardSanitizedWrite"] }),
+        )?;
+        let ret = self.execute_async_script(
+            r#"
+            let done = arguments[arguments.length - 1];
+            navigator.clipboard.readText().then(
+                (text) => done({ text: text }),
+                (e) => done({ error: String(e) })
+            );
+            "#,
+        )?;
+        match ret.value()["text"].as_str() {
+            Some(text) => Ok(text.to_string()),
+            None => Err(WebDriverError::JavascriptError(WebDriverErrorInfo::new(&format!(
+                "Failed to read clipboard: {}",
+                ret.value()["error"]
+            )))),
+        }
+    }
+
+    /// Write the specified text to the clipboard, e.g. to test pasting into the page.
+    ///
+    /// See `read_clipboard()` for the requirements. This is only supported on Chromium-based
+    /// browsers.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// driver.write_clipboard("pasted text")?;
+    /// let elem = driver.find_element(By::Name("input1"))?;
+    /// elem.send_keys(Keys::Control + "v")?;
+    /// ```
+    fn write_clipboard(&self, text: &str) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params(
+            "Browser.grantPermissions",
+            json!({ "permissions": ["clipboardReadWrite", "clipboardSanitizedWrite"] }),
+        )?;
+        let mut args = ScriptArgs::new();
+        args.push(text)?;
+        let ret = self.execute_async_script_with_args(
+            r#"
+            let done = arguments[arguments.length - 1];
+            navigator.clipboard.writeText(arguments[0]).then(
+                () => done(null),
+                (e) => done(String(e))
+            );
+            "#,
+            &args,
+        )?;
+        match ret.value().as_str() {
+            None => Ok(()),
+            Some(e) => Err(WebDriverError::JavascriptError(WebDriverErrorInfo::new(&format!(
+                "Failed to write clipboard: {}",
+                e
+            )))),
+        }
+    }
+
+    /// Disable all CSS transitions and animations on the current page, by injecting a
+    /// stylesheet that overrides them. CSS animations are a common cause of flaky clicks and
+    /// screenshots, since elements may still be moving when they are interacted with.
+    ///
+    /// The stylesheet only applies to the current page. To re-apply it automatically after
+    /// every call to `get()`, `back()`, `forward()` or `refresh()`, use
+    /// `WebDriver::set_disable_animations_on_navigate()`. Navigation within a single-page app
+    /// does not load a new page so the stylesheet is kept, but a full page load triggered by
+    /// the page itself (e.g. clicking a link) requires calling this again.
+    ///
+    /// Calling this more than once on the same page has no further effect. Animations driven
+    /// by Javascript rather than CSS are not affected.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.disable_animations()?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// #     assert_eq!(elem.computed_style("transition-duration")?, "0s");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn disable_animations(&self) -> WebDriverResult<()> {
+        self.execute_script(DISABLE_ANIMATIONS_SCRIPT)?;
+        Ok(())
+    }
+
+    /// Run the specified action and wait for it to open a new window or tab, returning the
+    /// handle of the new window.
+    ///
+    /// The existing window handles are recorded before the action is run, so a window that
+    /// opens immediately will not be missed. This does not switch to the new window.
+    /// If no new window appears before the timeout elapses, `WebDriverError::Timeout` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let handle = driver.expect_new_window(
+    ///     || {
+    ///         driver.execute_script(r#"window.open("about:blank", target="_blank");"#)?;
+    ///         Ok(())
+    ///     },
+    ///     Duration::from_secs(10),
+    /// )?;
+    /// driver.switch_to().window(&handle)?;
+    /// #     assert_eq!(driver.current_window_handle()?, handle);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn expect_new_window<F>(&self, action: F, timeout: Duration) -> WebDriverResult<WindowHandle>
+    where
+        Self: Sized,
+        F: FnOnce() -> WebDriverResult<()>,
+    {
+        let existing_handles = self.window_handles()?;
+        action()?;
+        self.wait_until(
+            |driver| {
+                let handles = driver.window_handles()?;
+                Ok(handles.into_iter().find(|h| !existing_handles.contains(h)))
+            },
+            timeout,
+        )
+        .map_err(|e| match e {
+            WebDriverError::Timeout(_) => WebDriverError::Timeout(format!(
+                "Timed out after {:?} waiting for a new window to open",
+                timeout
+            )),
+            e => e,
+        })
+    }
+
+    /// Override the timezone used by the browser, e.g. `"America/New_York"`.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Emulation.setTimezoneOverride` and is
+    /// therefore only supported on Chromium-based browsers. If the timezone ID is not
+    /// recognised by the browser, `WebDriverError::InvalidArgument` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// driver.set_timezone("Asia/Tokyo")?;
+    /// let ret = driver.execute_script(
+    ///     r#"return Intl.DateTimeFormat().resolvedOptions().timeZone;"#
+    /// )?;
+    /// let timezone: String = ret.convert()?;
+    /// assert_eq!(timezone, "Asia/Tokyo");
+    /// #     assert!(driver.set_timezone("Not/A_Timezone").is_err());
+    /// #     driver.clear_timezone_override()?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_timezone(&self, timezone_id: &str) -> WebDriverResult<()> {
+        if timezone_id.trim().is_empty() {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Timezone ID must not be empty. Use clear_timezone_override() instead",
+            )));
+        }
+
+        let dev_tools = ChromeDevTools::new(self.session());
+        match dev_tools.execute_cdp_with_params(
+            "Emulation.setTimezoneOverride",
+            json!({ "timezoneId": timezone_id }),
+        ) {
+            Ok(_) => Ok(()),
+            Err(e @ WebDriverError::UnknownError(_))
+            | Err(e @ WebDriverError::InvalidArgument(_)) => {
+                Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                    "Unable to set timezone '{}': {}",
+                    timezone_id, e
+                ))))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Remove any timezone override previously set via `set_timezone()`, reverting to the
+    /// browser's default timezone.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    fn clear_timezone_override(&self) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params(
+            "Emulation.setTimezoneOverride",
+            json!({ "timezoneId": "" }),
+        )?;
+        Ok(())
+    }
+
+    /// Override the browser locale, e.g. `"de-DE"`.
+    ///
+    /// This sets the locale via the Chrome DevTools Protocol command `Emulation.setLocaleOverride`,
+    /// which affects `navigator.language` and the `Intl` APIs. It also sets the `Accept-Language`
+    /// header for all subsequent requests via `Network.setExtraHTTPHeaders`, since many sites
+    /// determine the locale server-side from that header instead.
+    ///
+    /// Other extra HTTP headers set via this crate, such as the `Authorization` header set by
+    /// `set_basic_auth()`, are kept. Only the JS-visible locale is affected on pages that have
+    /// already been loaded, so you may need to reload the page after calling this.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.set_locale("de-DE")?;
+    /// driver.get("http://webappdemo")?;
+    /// let ret = driver.execute_script(r#"return navigator.language;"#)?;
+    /// let language: String = ret.convert()?;
+    /// assert_eq!(language, "de-DE");
+    /// #     driver.reset_locale()?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_locale(&self, locale: &str) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools
+            .execute_cdp_with_params("Emulation.setLocaleOverride", json!({ "locale": locale }))?;
+        set_extra_http_header(self, "Accept-Language", Some(locale.to_string()))
+    }
+
+    /// Remove any locale override previously set via `set_locale()`, including the
+    /// `Accept-Language` header override. Other extra HTTP headers are kept.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    fn reset_locale(&self) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params("Emulation.setLocaleOverride", json!({}))?;
+        set_extra_http_header(self, "Accept-Language", None)
+    }
+
+    /// Send HTTP basic authentication credentials with all subsequent requests, so that pages
+    /// protected by basic auth load without showing the browser's native login dialog (which
+    /// cannot be handled via WebDriver).
+    ///
+    /// This sets the `Authorization: Basic ...` header via the Chrome DevTools Protocol command
+    /// `Network.setExtraHTTPHeaders`, and is only supported on Chromium-based browsers.
+    ///
+    /// **NOTE:** The header is not scoped to any particular origin. The credentials will be
+    /// sent with every request the browser makes, including requests to third-party origins,
+    /// so only use this with test credentials. Other extra HTTP headers set via this crate, such
+    /// as the `Accept-Language` header set by `set_locale()`, are kept.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// driver.set_basic_auth("username", "password")?;
+    /// driver.get("https://staging.example.com")?;
+    /// // ...
+    /// driver.clear_basic_auth()?;
+    /// ```
+    fn set_basic_auth(&self, username: &str, password: &str) -> WebDriverResult<()> {
+        let credentials = encode(format!("{}:{}", username, password));
+        set_extra_http_header(self, "Authorization", Some(format!("Basic {}", credentials)))
+    }
+
+    /// Stop sending the basic authentication credentials set via `set_basic_auth()`.
+    ///
+    /// This removes only the `Authorization` header, keeping any other extra HTTP headers set
+    /// via this crate. This is only supported on Chromium-based browsers.
+    fn clear_basic_auth(&self) -> WebDriverResult<()> {
+        set_extra_http_header(self, "Authorization", None)
+    }
+
+    /// Intercept all `fetch()` and `XMLHttpRequest` calls made by the page to URLs matching the
+    /// specified pattern, and return the specified mock response instead. This is useful for
+    /// stubbing out backend APIs in UI tests.
+    ///
+    /// The URL pattern is matched against the full URL, where `*` matches zero or more
+    /// characters, e.g. `"*/api/users*"`. Adding another interceptor with the same pattern
+    /// replaces the existing one.
+    ///
+    /// The interceptor applies to the current page immediately, and is also registered via the
+    /// Chrome DevTools Protocol command `Page.addScriptToEvaluateOnNewDocument` so that it
+    /// applies to all pages loaded subsequently. This is only supported on Chromium-based
+    /// browsers.
+    ///
+    /// **NOTE:** Because this crate is synchronous and the WebDriver endpoint for CDP commands
+    /// cannot receive CDP events, requests cannot be paused and fulfilled dynamically via the
+    /// `Fetch` domain. Instead, the responses are fixed up-front and are served by patching
+    /// `fetch()` and `XMLHttpRequest` within the page. Requests made by the browser itself
+    /// (such as navigation, images, scripts and stylesheets) are not intercepted.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let interceptor = driver.add_network_interceptor(
+    ///     "*/api/users*",
+    ///     MockResponse::json(&serde_json::json!([{ "name": "Alice" }])),
+    /// )?;
+    /// driver.get("http://localhost:8080/users")?;
+    /// // The page now sees the mocked list of users.
+    /// driver.remove_network_interceptor(&interceptor)?;
+    /// ```
+    fn add_network_interceptor(
+        &self,
+        url_pattern: &str,
+        response: MockResponse,
+    ) -> WebDriverResult<NetworkInterceptor> {
+        let script = mock_script(url_pattern, &response);
+        let script_id = add_script_on_new_document(self, &script)?;
+        Ok(NetworkInterceptor {
+            url_pattern: url_pattern.to_string(),
+            script_id,
+        })
+    }
+
+    /// Remove a network interceptor previously added via `add_network_interceptor()`, both
+    /// from the current page and from all pages loaded subsequently.
+    ///
+    /// This is only supported on Chromium-based browsers.
+    fn remove_network_interceptor(&self, interceptor: &NetworkInterceptor) -> WebDriverResult<()> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp_with_params(
+            "Page.removeScriptToEvaluateOnNewDocument",
+            json!({ "identifier": interceptor.script_id }),
+        )?;
+        self.execute_script(&unmock_script(&interceptor.url_pattern))?;
+        Ok(())
+    }
+
+    /// Get the full accessibility tree for the current page, as computed by the browser.
+    ///
+    /// This uses the Chrome DevTools Protocol command `Accessibility.getFullAXTree`, and is
+    /// therefore only supported on Chromium-based browsers. The `Accessibility` domain is
+    /// enabled automatically before the tree is requested, since otherwise the browser may not
+    /// have computed the accessible names yet.
+    ///
+    /// The returned root node can be pretty-printed via its `Display` implementation, and
+    /// `AccessibilityNode::find()` can be used to search the tree by role and name.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("button1"))?;
+    /// let tree = driver.accessibility_tree()?;
+    /// println!("{}", tree);
+    /// assert!(tree.find("button", "BUTTON 1").is_some());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn accessibility_tree(&self) -> WebDriverResult<AccessibilityNode> {
+        let dev_tools = ChromeDevTools::new(self.session());
+        dev_tools.execute_cdp("Accessibility.enable")?;
+        let ret = dev_tools.execute_cdp("Accessibility.getFullAXTree")?;
+        let nodes = ret["nodes"].as_array().map(|nodes| nodes.as_slice()).unwrap_or_default();
+        AccessibilityNode::from_cdp_nodes(nodes).ok_or_else(|| {
+            WebDriverError::NotFound(
+                "accessibility tree".to_string(),
+                "The browser did not return a root accessibility node".to_string(),
+            )
+        })
+    }
+
+    /// Return true if pressing the Tab key moves the keyboard focus through the specified
+    /// elements in order, otherwise false.
+    ///
+    /// The first element is focused directly, then Tab is pressed once for each subsequent
+    /// element and the focus is checked after each key press. This stops at the first
+    /// element that does not receive focus. An empty slice is always considered to match.
+    ///
+    /// Note that this leaves the focus on the last element that was checked.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
+    /// let input1 = driver.find_element(By::Name("input1"))?;
+    /// let input2 = driver.find_element(By::Name("input2"))?;
+    /// assert!(driver.tab_order(&[input1, input2])?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn tab_order(&self, elements: &[WebElement]) -> WebDriverResult<bool> {
+        let (first, rest) = match elements.split_first() {
+            Some(x) => x,
+            None => return Ok(true),
+        };
+
+        let mut args = ScriptArgs::new();
+        args.push(first)?;
+        self.execute_script_with_args(r#"arguments[0].focus();"#, &args)?;
+        if !first.is_focused()? {
+            return Ok(false);
+        }
+
+        for elem in rest {
+            self.action_chain().key_down(Keys::Tab).key_up(Keys::Tab).perform()?;
+            if !elem.is_focused()? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Repeatedly scroll to the bottom of the page until the page stops growing, e.g. to
+    /// load all items in an infinite-scroll feed. Returns the number of scrolls performed.
+    ///
+    /// After each scroll, this waits for `settle` to give the page time to load more content,
+    /// and then compares the scroll height of the document with the height before the scroll.
+    /// Scrolling stops once the height no longer increases, or after `max_iterations` scrolls.
+    ///
+    /// The settle time is a tradeoff: if it is too short, this may stop before the next batch
+    /// of content has loaded, but every scroll takes at least this long, including the final
+    /// scroll that confirms there is nothing more to load.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// let scrolls = driver.scroll_to_bottom(10, Duration::from_millis(500))?;
+    /// #     assert!(scrolls >= 1);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn scroll_to_bottom(&self, max_iterations: u32, settle: Duration) -> WebDriverResult<u32> {
+        let height_script = r#"return document.documentElement.scrollHeight;"#;
+        let mut height: u64 = self.execute_script(height_script)?.convert()?;
+        let mut scrolls = 0;
+        while scrolls < max_iterations {
+            self.execute_script(r#"window.scrollTo(0, document.documentElement.scrollHeight);"#)?;
+            scrolls += 1;
+            sleep(settle);
+
+            let new_height: u64 = self.execute_script(height_script)?.convert()?;
+            if new_height <= height {
+                break;
+            }
+            height = new_height;
+        }
+        Ok(scrolls)
+    }
+
+    /// Start recording uncaught Javascript errors and unhandled promise rejections on the
+    /// current page. Use `collect_js_errors()` to retrieve them.
+    ///
+    /// This works on all browsers, unlike reading the browser log. However, errors thrown
+    /// before this is called are not recorded, and the collector only applies to the current
+    /// page, so it must be installed again after each navigation. Calling this again on the
+    /// same page has no effect.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// driver.install_error_collector()?;
+    /// driver.find_element(By::Id("button1"))?.click()?;
+    /// let errors = driver.collect_js_errors()?;
+    /// assert!(errors.is_empty(), "Page threw errors: {:?}", errors);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn install_error_collector(&self) -> WebDriverResult<()> {
+        self.execute_script(INSTALL_ERROR_COLLECTOR_SCRIPT)?;
+        Ok(())
+    }
+
+    /// Return the Javascript errors recorded since `install_error_collector()` was called,
+    /// or since the last call to this method, and clear them.
+    ///
+    /// Returns `WebDriverError::NotFound` if the error collector is not installed on the
+    /// current page, e.g. because the page has navigated since it was installed. This
+    /// ensures that a missing collector is not mistaken for a page without errors.
+    fn collect_js_errors(&self) -> WebDriverResult<Vec<JsError>> {
+        let ret = self.execute_script(COLLECT_ERRORS_SCRIPT)?;
+        if ret.value().is_null() {
+            return Err(WebDriverError::NotFound(
+                "error collector".to_string(),
+                "The error collector is not installed on the current page. \
+                 Call install_error_collector() after each navigation"
+                    .to_string(),
+            ));
+        }
+        ret.convert()
+    }
+
+    /// Get the URLs of all links (`<a href>` elements) on the current page, in document order.
+    ///
+    /// The URLs are resolved against the base URL of the document, so relative links are
+    /// returned as absolute URLs. Links with an empty `href` or a `javascript:` URL are
+    /// excluded. All links are fetched in a single request. To get the link text as well,
+    /// or to change the filtering, use `all_links_with_text()`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// let links = driver.all_links()?;
+    /// for link in &links {
+    ///     println!("{}", link);
+    /// }
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn all_links(&self) -> WebDriverResult<Vec<String>> {
+        let links = self.all_links_with_text(LinkOptions::default())?;
+        Ok(links.into_iter().map(|link| link.href).collect())
+    }
+
+    /// Get the text and URL of all links (`<a href>` elements) on the current page, in
+    /// document order, filtered according to the specified `LinkOptions`.
+    ///
+    /// The URLs are resolved against the base URL of the document. All links are fetched
+    /// in a single request.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// use thirtyfour_sync::LinkOptions;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// let links = driver.all_links_with_text(LinkOptions::new().include_javascript(true))?;
+    /// for link in &links {
+    ///     println!("{}: {}", link.text, link.href);
+    /// }
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn all_links_with_text(&self, options: LinkOptions) -> WebDriverResult<Vec<Link>> {
+        let mut args = ScriptArgs::new();
+        args.push(options.include_empty)?;
+        args.push(options.include_javascript)?;
+        let ret = self.execute_script_with_args(ALL_LINKS_SCRIPT, &args)?;
+        ret.convert()
+    }
+
+    /// Check all links on the current page, and return the HTTP status code for each one,
+    /// or `None` if the request failed (e.g. due to a timeout or a DNS error).
+    ///
+    /// The links are collected via `all_links()`. Only `http` and `https` links are checked,
+    /// so links such as `mailto:` and `tel:` are skipped. The fragment is removed from each
+    /// URL, and each URL is only checked once. Each link is requested with a HEAD request,
+    /// falling back to a GET request if the server does not support HEAD.
+    ///
+    /// The requests are made from this process with a 10 second timeout, not from the
+    /// browser, so they do not share the cookies or credentials of the browser session.
+    /// To customise this, use `check_links_with_client()`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// driver.get("http://webappdemo")?;
+    /// for (url, status) in driver.check_links()? {
+    ///     match status {
+    ///         Some(200..=399) => {}
+    ///         Some(code) => println!("Broken link: {} ({})", url, code),
+    ///         None => println!("Broken link: {} (request failed)", url),
+    ///     }
+    /// }
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn check_links(&self) -> WebDriverResult<Vec<(String, Option<u16>)>> {
+        let client =
+            reqwest::blocking::Client::builder().timeout(Duration::from_secs(10)).build()?;
+        self.check_links_with_client(&client)
+    }
+
+    /// Check all links on the current page using the specified HTTP client, and return the
+    /// HTTP status code for each one, or `None` if the request failed.
+    ///
+    /// This is useful for setting a different timeout, or default headers such as cookies.
+    /// See `check_links()` for more details.
+    fn check_links_with_client(
+        &self,
+        client: &reqwest::blocking::Client,
+    ) -> WebDriverResult<Vec<(String, Option<u16>)>> {
+        let mut seen = HashSet::new();
+        let mut results = Vec::new();
+        for link in self.all_links()? {
+            let url = link.split('#').next().unwrap_or_default();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                continue;
+            }
+            if seen.insert(url.to_string()) {
+                results.push((url.to_string(), link_status(client, url)));
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// The type of a browser window, as returned by
+/// [WebDriver::window_handle_type()](struct.WebDriver.html#method.window_handle_type).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowHandleType {
+    /// A normal browser tab or window.
+    Tab,
+    /// A window that was opened by another window, e.g. via `window.open()`.
+    Popup,
+    /// The type could not be determined.
+    Unknown,
+}
+
+/// The value of the `prefers-color-scheme` media feature, for use with
+/// [WebDriver::set_color_scheme()](struct.WebDriver.html#method.set_color_scheme).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// Light mode.
+    Light,
+    /// Dark mode.
+    Dark,
+}
+
+impl ColorScheme {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ColorScheme::Light => "light",
+            ColorScheme::Dark => "dark",
+        }
+    }
+}
+
+/// Helper struct for getting return values from scripts.
+/// See the examples for [WebDriver::execute_script()](struct.WebDriver.html#method.execute_script)
+/// and [WebDriver::execute_async_script()](struct.WebDriver.html#method.execute_async_script).
+pub struct ScriptRetSync<'a> {
+    driver: &'a WebDriverSession,
+    value: Value,
+}
+
+impl<'a> ScriptRetSync<'a> {
+    /// Create a new ScriptRetSync. This is typically done automatically via
+    /// [WebDriver::execute_script()](struct.WebDriver.html#method.execute_script)
+    /// or [WebDriver::execute_async_script()](struct.WebDriver.html#method.execute_async_script)
+    pub fn new(driver: &'a WebDriverSession, value: Value) -> Self {
+        ScriptRetSync {
+            driver,
+            value,
+        }
+    }
+
+    /// Get the raw JSON value.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
 
     pub fn convert<T>(&self) -> WebDriverResult<T>
     where
@@ -1172,6 +3588,48 @@ impl<'a> ScriptRetSync<'a> {
         Ok(v)
     }
 
+    /// Convert an array returned by the script into a tuple, e.g. `(String, u32, bool)`.
+    ///
+    /// This is a convenient way to return several values from a single script call without
+    /// defining a struct. Your script must return an array with exactly one element per
+    /// tuple field, otherwise an error is returned. If the script did not return an array,
+    /// `WebDriverError::InvalidArgument` is returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("button1"))?;
+    /// let ret = driver.execute_script(r#"
+    ///     let elem = document.getElementById("button1");
+    ///     return [elem.innerText, elem.tagName, elem.disabled];
+    ///     "#
+    /// )?;
+    /// let (text, tag, disabled): (String, String, bool) = ret.as_tuple()?;
+    /// assert_eq!(text, "BUTTON 1");
+    /// assert_eq!(tag, "BUTTON");
+    /// assert!(!disabled);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_tuple<T>(&self) -> WebDriverResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        if !self.value.is_array() {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                "Script did not return an array: {}",
+                self.value
+            ))));
+        }
+        self.convert()
+    }
+
     /// Get a single WebElement return value.
     /// Your script must return only a single element for this to work.
     pub fn get_element(&self) -> WebDriverResult<WebElement> {
@@ -1183,4 +3641,39 @@ impl<'a> ScriptRetSync<'a> {
     pub fn get_elements(&self) -> WebDriverResult<Vec<WebElement>> {
         convert_elements_sync(self.driver, &self.value)
     }
+
+    /// Return an ElementQuery for finding elements within the element returned by the
+    /// script. Your script must return only a single element for this to work, otherwise
+    /// `WebDriverError::InvalidArgument` is returned.
+    ///
+    /// This is useful for locating a container via Javascript, and then using the polling
+    /// and filtering of the query interface to find elements inside it.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let ret = driver.execute_script(
+    ///     r#"return document.querySelector("div[data-section='section-buttons']");"#,
+    /// )?;
+    /// let elem = ret.query_within(By::Id("button1"))?.first()?;
+    /// #     assert_eq!(elem.tag_name()?, "button");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn query_within(&self, by: By<'a>) -> WebDriverResult<ElementQuery<'a>> {
+        let element = convert_element_sync(self.driver, &self.value).map_err(|_| {
+            WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                "Script did not return a single element: {}",
+                self.value
+            )))
+        })?;
+        let poller = self.driver.config().query_poller.clone();
+        Ok(ElementQuery::new(ElementQuerySource::OwnedElement(element), poller, by))
+    }
 }
@@ -1,17 +1,29 @@
-use std::{fmt, fs::File, io::Write, path::Path, write};
+use std::{
+    fmt,
+    fs::File,
+    hash::{Hash, Hasher},
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+    write,
+};
 
 use base64::decode;
+use image::{ImageFormat, ImageOutputFormat};
 use serde::ser::{Serialize, SerializeMap, Serializer};
 
+use crate::action_chain::TouchAction;
 use crate::common::command::MAGIC_ELEMENTID;
-use crate::error::WebDriverError;
+use crate::error::{WebDriverError, WebDriverErrorInfo};
+use crate::query::ElementWaitable;
+use crate::validity::{ValidityState, VALIDITY_SCRIPT};
 use crate::webdrivercommands::WebDriverCommands;
 use crate::WebDriverSession;
 use crate::{
     common::{
         command::Command,
         connection_common::convert_json,
-        keys::TypingData,
+        keys::{Keys, TypingData},
         types::{ElementId, ElementRect, ElementRef},
     },
     error::WebDriverResult,
@@ -78,6 +90,13 @@ pub fn convert_elements_sync<'a>(
 /// Elements can be clicked using the `click()` method, and you can send
 /// input to an element using the `send_keys()` method.
 ///
+/// ## Equality
+///
+/// Two WebElements are equal if they have the same session id and element id, so they can be
+/// deduplicated or used as keys in a `HashMap` or `HashSet`. This is reference identity, not
+/// DOM node identity: the WebDriver spec does not guarantee that two different find operations
+/// for the same DOM node will return the same element id. To check whether two elements refer
+/// to the same DOM node, use `WebElement::is_same_node()` instead.
 #[derive(Debug, Clone)]
 pub struct WebElement<'a> {
     pub element_id: ElementId,
@@ -195,6 +214,82 @@ impl<'a> WebElement<'a> {
         convert_json(&v["value"])
     }
 
+    /// Get the text contents for this WebElement, or an empty string if the element is
+    /// stale or no longer exists.
+    ///
+    /// This is lossy: an element that has been removed from the page is indistinguishable
+    /// from an element with no text. It is intended for best-effort text extraction, such
+    /// as looping over a list that may be changing, where a transient stale reference should
+    /// not abort the whole loop. Any other error is still returned.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elems = driver.find_elements(By::Tag("button"))?;
+    /// let texts: Vec<String> =
+    ///     elems.iter().map(|e| e.text_or_empty()).collect::<WebDriverResult<_>>()?;
+    /// #     assert!(texts.contains(&"BUTTON 1".to_string()));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn text_or_empty(&self) -> WebDriverResult<String> {
+        match self.text() {
+            Ok(text) => Ok(text),
+            Err(WebDriverError::StaleElementReference(_))
+            | Err(WebDriverError::NoSuchElement(_)) => Ok(String::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the text contents for this WebElement, with leading and trailing whitespace
+    /// removed.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// assert_eq!(elem.text_trimmed()?, "BUTTON 1");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn text_trimmed(&self) -> WebDriverResult<String> {
+        Ok(self.text()?.trim().to_string())
+    }
+
+    /// Return true if the text contents of this WebElement are equal to the specified text,
+    /// ignoring case and any leading or trailing whitespace on either side.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// assert!(elem.text_eq_ignore_case(" Button 1 ")?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn text_eq_ignore_case(&self, other: &str) -> WebDriverResult<bool> {
+        let text = self.text()?;
+        Ok(text.trim().to_lowercase() == other.trim().to_lowercase())
+    }
+
     /// Convenience method for getting the (optional) value attribute of this element.
     pub fn value(&self) -> WebDriverResult<Option<String>> {
         self.get_attribute("value")
@@ -223,6 +318,69 @@ impl<'a> WebElement<'a> {
         Ok(())
     }
 
+    /// Click at the specified offset (in pixels) from the top-left corner of this WebElement.
+    ///
+    /// This is useful for clicking on a specific region of a larger element, such as a chart,
+    /// map or slider. Note that the offset is relative to the top-left corner of the element
+    /// rather than the center.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// // Click 5 pixels right and 5 pixels down from the top-left corner.
+    /// elem.click_at_offset(5, 5)?;
+    /// #     let elem = driver.find_element(By::Id("button-result"))?;
+    /// #     assert_eq!(elem.text()?, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn click_at_offset(&self, x_offset: i32, y_offset: i32) -> WebDriverResult<()> {
+        // W3C pointer offsets are relative to the center of the element.
+        let rect = self.rect()?;
+        // Round rather than truncate, so odd-sized elements are not off by a pixel.
+        let x = (x_offset as f64 - rect.width / 2.0).round() as i32;
+        let y = (y_offset as f64 - rect.height / 2.0).round() as i32;
+        self.session.action_chain().move_to_element_with_offset(self, x, y).click().perform()
+    }
+
+    /// Drag this WebElement and drop it at the specified X and Y coordinates.
+    ///
+    /// This clicks and holds the center of the element, moves the mouse to the specified
+    /// point, and then releases it. This is useful for dropping onto a region that is not an
+    /// element, such as a trash zone at a fixed position.
+    ///
+    /// The coordinates are in CSS pixels relative to the top-left corner of the viewport,
+    /// not the document, so they depend on the current scroll position. The point must be
+    /// inside the viewport, otherwise the WebDriver server returns an error.
+    ///
+    /// **NOTE:** Pointer actions do not trigger HTML5 drag-and-drop events (`dragstart`,
+    /// `drop` etc.) in most browsers, so this works for mouse-event based dragging only.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.drag_to_point(10, 10)?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn drag_to_point(&self, x: i32, y: i32) -> WebDriverResult<()> {
+        self.session.action_chain().click_and_hold_element(self).move_to(x, y).release().perform()
+    }
+
     /// Clear the WebElement contents.
     ///
     /// # Example:
@@ -307,6 +465,55 @@ impl<'a> WebElement<'a> {
         }
     }
 
+    /// Set the specified attribute on this element, using `setAttribute()` in Javascript.
+    ///
+    /// WebDriver has no command for setting attributes, so this mutates the live DOM
+    /// directly. The page will not be notified other than via any mutation observers,
+    /// which is useful for testing components that react to data attributes, or forcing
+    /// a particular state before taking a screenshot.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.set_attribute("data-state", "active")?;
+    /// assert_eq!(elem.get_attribute("data-state")?, Some("active".to_string()));
+    /// elem.remove_attribute("data-state")?;
+    /// assert_eq!(elem.get_attribute("data-state")?, None);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_attribute(&self, name: &str, value: &str) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(name)?;
+        args.push(value)?;
+        self.session.execute_script_with_args(
+            r#"arguments[0].setAttribute(arguments[1], arguments[2]);"#,
+            &args,
+        )?;
+        Ok(())
+    }
+
+    /// Remove the specified attribute from this element, using `removeAttribute()` in
+    /// Javascript. Like `set_attribute()`, this mutates the live DOM directly.
+    ///
+    /// Removing an attribute that is not present does nothing.
+    pub fn remove_attribute(&self, name: &str) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(name)?;
+        self.session
+            .execute_script_with_args(r#"arguments[0].removeAttribute(arguments[1]);"#, &args)?;
+        Ok(())
+    }
+
     /// Get the specified CSS property.
     ///
     /// # Example:
@@ -335,6 +542,51 @@ impl<'a> WebElement<'a> {
         }
     }
 
+    /// Get the computed value of the specified CSS property, as returned by
+    /// `window.getComputedStyle()` in Javascript.
+    ///
+    /// Unlike `get_css_property()`, which uses the WebDriver endpoint, this always returns the
+    /// value as computed by the browser itself. The property may be specified either in CSS
+    /// notation (e.g. `"z-index"`) or Javascript notation (e.g. `"zIndex"`).
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
+    /// #     let elem = driver.find_element(By::Name("input2"))?;
+    /// let css_color = elem.computed_style("color")?;
+    /// assert_eq!(css_color, r"rgb(0, 0, 0)");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn computed_style(&self, property: &str) -> WebDriverResult<String> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(property)?;
+        let ret = self.session.execute_script_with_args(
+            r#"
+            let style = window.getComputedStyle(arguments[0]);
+            return style.getPropertyValue(arguments[1]) || style[arguments[1]] || "";
+            "#,
+            &args,
+        )?;
+        ret.convert()
+    }
+
+    /// Get the computed z-index of this element, or `None` if the z-index is `auto`.
+    ///
+    /// This is useful when debugging issues where an element is obscured by another element,
+    /// such as an overlay intercepting clicks.
+    pub fn z_index(&self) -> WebDriverResult<Option<i32>> {
+        Ok(self.computed_style("z-index")?.parse::<i32>().ok())
+    }
+
     /// Return true if the WebElement is currently selected, otherwise false.
     pub fn is_selected(&self) -> WebDriverResult<bool> {
         let v = self.cmd(Command::IsElementSelected(self.element_id.clone()))?;
@@ -407,6 +659,67 @@ impl<'a> WebElement<'a> {
         Ok(self.is_displayed()? && self.is_enabled()?)
     }
 
+    /// Return true if the WebElement currently has keyboard focus, otherwise false.
+    ///
+    /// This compares the element with `document.activeElement` in the browser, so it does
+    /// not depend on element ids and works regardless of how the element was found.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
+    /// let elem = driver.find_element(By::Name("input1"))?;
+    /// elem.click()?;
+    /// assert!(elem.is_focused()?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_focused(&self) -> WebDriverResult<bool> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self.session.execute_script_with_args(
+            r#"return arguments[0] === document.activeElement;"#,
+            &args,
+        )?;
+        ret.convert()
+    }
+
+    /// Wait up to the specified timeout for this element to be clickable (displayed and
+    /// enabled), and return it so that it can be used in a chain.
+    ///
+    /// Returns `WebDriverError::Timeout` if the element is not clickable within the timeout.
+    /// This uses the polling interval of the query poller from the config.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.ensure_clickable(Duration::from_secs(5))?.click()?;
+    /// #     let elem_result = driver.find_element(By::Id("button-result"))?;
+    /// #     assert_eq!(elem_result.text()?, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn ensure_clickable(&self, timeout: Duration) -> WebDriverResult<&Self> {
+        self.wait_until()
+            .error(&format!("Timed out waiting for element to be clickable: {}", self))
+            .clickable_within(timeout)?;
+        Ok(self)
+    }
+
     /// Return true if the WebElement is currently (still) present
     /// and not stale.
     ///
@@ -448,6 +761,106 @@ impl<'a> WebElement<'a> {
         Ok(present)
     }
 
+    /// Run the specified function with this element, and run it again once if it fails with
+    /// `WebDriverError::StaleElementReference`.
+    ///
+    /// If `relocate` is specified, the element is found again from the document root using
+    /// this selector before retrying, and the new element is passed to the function.
+    /// Otherwise the function is passed the same element again, which is only useful if the
+    /// function finds the elements it needs itself (e.g. child elements).
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let text = elem.retry_on_stale(Some(By::Id("button1")), |elem| elem.text())?;
+    /// #     assert_eq!(text, "BUTTON 1");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn retry_on_stale<F, T>(&self, relocate: Option<By>, f: F) -> WebDriverResult<T>
+    where
+        F: Fn(&WebElement) -> WebDriverResult<T>,
+    {
+        self.retry_on_stale_times(1, relocate, f)
+    }
+
+    /// Run the specified function with this element, and retry it up to the specified number
+    /// of times while it fails with `WebDriverError::StaleElementReference`.
+    ///
+    /// See `retry_on_stale()` for more details.
+    pub fn retry_on_stale_times<F, T>(
+        &self,
+        retries: u32,
+        relocate: Option<By>,
+        f: F,
+    ) -> WebDriverResult<T>
+    where
+        F: Fn(&WebElement) -> WebDriverResult<T>,
+    {
+        let mut relocated: Option<WebElement> = None;
+        let mut attempts = 0;
+        loop {
+            let result = f(relocated.as_ref().unwrap_or(self));
+            match result {
+                Err(WebDriverError::StaleElementReference(_)) if attempts < retries => {
+                    attempts += 1;
+                    if let Some(by) = &relocate {
+                        relocated = Some(self.session.find_element(by.clone())?);
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Get the HTML5 constraint validation state of this element, as reported by its
+    /// `validity` property.
+    ///
+    /// Returns `WebDriverError::InvalidArgument` if the element does not support constraint
+    /// validation (i.e. it is not a form element such as `<input>` or `<select>`).
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let elem = driver.find_element(By::Name("email"))?;
+    /// elem.send_keys("not an email address")?;
+    /// let validity = elem.validity()?;
+    /// assert!(!validity.valid);
+    /// assert!(validity.type_mismatch);
+    /// ```
+    pub fn validity(&self) -> WebDriverResult<ValidityState> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self.session.execute_script_with_args(VALIDITY_SCRIPT, &args)?;
+        if ret.value().is_null() {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Element does not support constraint validation",
+            )));
+        }
+        ret.convert()
+    }
+
+    /// Get the message the browser would display to describe why this element fails
+    /// validation, as reported by its `validationMessage` property.
+    ///
+    /// This returns an empty string if the element is valid or does not support constraint
+    /// validation. The message text is localised by the browser, so it is generally better
+    /// to assert on `validity()` and only use this for diagnostics.
+    pub fn validation_message(&self) -> WebDriverResult<String> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self
+            .session
+            .execute_script_with_args(r#"return arguments[0].validationMessage || "";"#, &args)?;
+        ret.convert()
+    }
+
     /// Search for a child element of this WebElement using the specified
     /// selector.
     ///
@@ -468,7 +881,7 @@ impl<'a> WebElement<'a> {
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn find_element(&self, by: By) -> WebDriverResult<WebElement> {
+    pub fn find_element(&self, by: By) -> WebDriverResult<WebElement<'a>> {
         let v = self
             .cmd(Command::FindElementFromElement(self.element_id.clone(), by.get_w3c_selector()))?;
         convert_element_sync(self.session, &v["value"])
@@ -495,7 +908,7 @@ impl<'a> WebElement<'a> {
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn find_elements(&self, by: By) -> WebDriverResult<Vec<WebElement>> {
+    pub fn find_elements(&self, by: By) -> WebDriverResult<Vec<WebElement<'a>>> {
         let v = self.cmd(Command::FindElementsFromElement(
             self.element_id.clone(),
             by.get_w3c_selector(),
@@ -503,8 +916,48 @@ impl<'a> WebElement<'a> {
         convert_elements_sync(self.session, &v["value"])
     }
 
+    /// Search for all child elements of this WebElement that match the specified selector,
+    /// and return the text of each one.
+    ///
+    /// This is much faster than calling `text()` on each element in turn, since the text of
+    /// all matching elements is read using a single script, rather than one request per
+    /// element. The text is read from the `innerText` property of each element, which
+    /// matches the rendered text returned by `text()` in most cases.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Css("div[data-section='section-buttons']"))?;
+    /// let texts = elem.child_texts(By::Tag("button"))?;
+    /// #     assert_eq!(texts[0], "BUTTON 1");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn child_texts(&self, by: By) -> WebDriverResult<Vec<String>> {
+        let elems = self.find_elements(by)?;
+        if elems.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut args = ScriptArgs::new();
+        args.push(&elems)?;
+        let ret = self.session.execute_script_with_args(
+            r#"return arguments[0].map((elem) => elem.innerText);"#,
+            &args,
+        )?;
+        ret.convert()
+    }
+
     /// Send the specified input.
     ///
+    /// To select files in an `<input type="file">` element, see `upload_files()`.
+    ///
     /// # Example:
     /// You can specify anything that implements `Into<TypingData>`. This
     /// includes &str and String.
@@ -550,29 +1003,380 @@ impl<'a> WebElement<'a> {
         Ok(())
     }
 
-    /// Take a screenshot of this WebElement and return it as a base64-encoded
-    /// String.
-    pub fn screenshot_as_base64(&self) -> WebDriverResult<String> {
-        let v = self.cmd(Command::TakeElementScreenshot(self.element_id.clone()))?;
-        convert_json(&v["value"])
-    }
-
-    /// Take a screenshot of this WebElement and return it as PNG bytes.
-    pub fn screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
-        let s = self.screenshot_as_base64()?;
-        let bytes: Vec<u8> = decode(&s)?;
-        Ok(bytes)
-    }
-
-    /// Take a screenshot of this WebElement and write it to the specified
-    /// filename.
-    pub fn screenshot(&self, path: &Path) -> WebDriverResult<()> {
-        let png = self.screenshot_as_png()?;
-        let mut file = File::create(path)?;
-        file.write_all(&png)?;
+    /// Press and release the specified key while this element has focus.
+    ///
+    /// This is equivalent to `send_keys(key)`, but reads more clearly for single special
+    /// keys such as `Keys::Enter` or `Keys::Escape`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
+    /// #     let elem = driver.find_element(By::Name("input1"))?;
+    /// elem.send_keys("selenium")?;
+    /// elem.press_key(Keys::Backspace)?;
+    /// #     assert_eq!(elem.value()?, Some("seleniu".to_string()));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn press_key(&self, key: Keys) -> WebDriverResult<()> {
+        self.send_keys(key)
+    }
+
+    /// Press the specified keys in order, in a single command.
+    ///
+    /// As with `send_keys()`, modifier keys such as `Keys::Control` or `Keys::Shift` stay
+    /// pressed until the end of the sequence, so `&[Keys::Control, Keys::Enter]` sends
+    /// Ctrl+Enter.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("pagetextinput"))?.click()?;
+    /// #     let elem = driver.find_element(By::Name("input1"))?;
+    /// elem.send_keys("selenium")?;
+    /// elem.press_keys(&[Keys::Backspace, Keys::Backspace, Keys::Home, Keys::Delete])?;
+    /// #     assert_eq!(elem.value()?, Some("eleni".to_string()));
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn press_keys(&self, keys: &[Keys]) -> WebDriverResult<()> {
+        let keys = keys.iter().cloned().fold(TypingData::from(""), |acc, key| acc + key);
+        self.send_keys(keys)
+    }
+
+    /// Select the specified file(s) in an `<input type="file">` element.
+    ///
+    /// As per the W3C WebDriver spec, multiple files are sent as newline-separated paths.
+    /// Selecting more than one file requires the input to have the `multiple` attribute.
+    ///
+    /// **NOTE:** The paths must be valid on the machine running the browser. If you are using a
+    /// remote Selenium server or Grid, the files must already exist on that machine.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// use std::path::Path;
+    ///
+    /// let elem = driver.find_element(By::Css("input[type='file']"))?;
+    /// elem.upload_files(&[Path::new("/tmp/file1.txt"), Path::new("/tmp/file2.txt")])?;
+    /// ```
+    pub fn upload_files<P>(&self, paths: &[P]) -> WebDriverResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let is_file_input = self.tag_name()?.eq_ignore_ascii_case("input")
+            && self.get_attribute("type")?.filter(|x| x.eq_ignore_ascii_case("file")).is_some();
+        if !is_file_input {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Files can only be uploaded to an <input type=\"file\"> element",
+            )));
+        }
+
+        if paths.is_empty() {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "At least one file path must be specified",
+            )));
+        }
+
+        let multiple = self.get_attribute("multiple")?.filter(|x| x != "false").is_some();
+        if paths.len() > 1 && !multiple {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Multiple files can only be uploaded to a file input with the 'multiple' attribute",
+            )));
+        }
+
+        let paths: Vec<String> =
+            paths.iter().map(|p| p.as_ref().to_string_lossy().into_owned()).collect();
+        self.send_keys(paths.join("\n"))
+    }
+
+    /// Drop the specified file onto this element, as if it had been dragged from the desktop.
+    ///
+    /// This is intended for drag-and-drop upload zones that do not have an
+    /// `<input type="file">`. A temporary hidden file input is added to the page and the file
+    /// is selected in it via `upload_files()`. Synthetic `dragenter`, `dragover` and `drop`
+    /// events carrying the file in a `DataTransfer` are then dispatched on this element, and
+    /// the temporary input is removed.
+    ///
+    /// **NOTE:** The path must be valid on the machine running the browser, as for
+    /// `upload_files()`. Since the events are synthetic, they will have `isTrusted` set to false.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// use std::path::Path;
+    ///
+    /// let elem = driver.find_element(By::Id("dropzone"))?;
+    /// elem.drop_file(Path::new("/tmp/file1.txt"))?;
+    /// ```
+    pub fn drop_file(&self, path: &Path) -> WebDriverResult<()> {
+        let ret = self.session.execute_script(
+            r#"
+            let input = document.createElement("input");
+            input.type = "file";
+            input.style.cssText = "position: fixed; top: 0; width: 1px; height: 1px; opacity: 0;";
+            document.body.appendChild(input);
+            return input;
+            "#,
+        )?;
+        let input = ret.get_element()?;
+        let result = input.upload_files(&[path]);
+
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(&input)?;
+        let dropped = self.session.execute_script_with_args(
+            r#"
+            let target = arguments[0];
+            let input = arguments[1];
+            let files = input.files;
+            input.remove();
+            if (!files || files.length === 0) {
+                return false;
+            }
+            let dataTransfer = new DataTransfer();
+            for (let file of files) {
+                dataTransfer.items.add(file);
+            }
+            for (let type of ["dragenter", "dragover", "drop"]) {
+                let event = new DragEvent(type, {
+                    bubbles: true,
+                    cancelable: true,
+                    dataTransfer: dataTransfer
+                });
+                target.dispatchEvent(event);
+            }
+            return true;
+            "#,
+            &args,
+        );
+
+        // Report the upload error first, since that would also explain why nothing was dropped.
+        result?;
+        if !dropped?.convert::<bool>()? {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                "Unable to select file for dropping: {}",
+                path.display()
+            ))));
+        }
+        Ok(())
+    }
+
+    /// Set the value of an `<input type="range">` slider element.
+    ///
+    /// The value is snapped to the nearest `step` and clamped between the `min` and `max`
+    /// attributes of the slider (which default to 0, 100 and 1 respectively, as per the HTML spec).
+    ///
+    /// The slider is first moved by clicking on the calculated position of the thumb using an
+    /// action chain. If the resulting value does not match (for example due to custom styling of
+    /// the slider) then the arrow keys are used to adjust the value one step at a time.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let elem = driver.find_element(By::Css("input[type='range']"))?;
+    /// elem.set_slider_value(25.0)?;
+    /// assert_eq!(elem.value()?, Some("25".to_string()));
+    /// ```
+    pub fn set_slider_value(&self, value: f64) -> WebDriverResult<()> {
+        let parse_attr = |name: &str, default: f64| -> WebDriverResult<f64> {
+            Ok(self.get_attribute(name)?.and_then(|x| x.parse::<f64>().ok()).unwrap_or(default))
+        };
+        let min = parse_attr("min", 0.0)?;
+        let max = parse_attr("max", 100.0)?;
+        let step = parse_attr("step", 1.0)?;
+        if max <= min || step <= 0.0 {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                "Invalid slider range: min={}, max={}, step={}",
+                min, max, step
+            ))));
+        }
+
+        let target = (min + ((value - min) / step).round() * step).clamp(min, max);
+        let current_value = || -> WebDriverResult<f64> {
+            Ok(self.value()?.and_then(|x| x.parse::<f64>().ok()).unwrap_or(min))
+        };
+
+        // Strategy 1: Click on the position of the thumb for the target value.
+        let rect = self.rect()?;
+        let ratio = (target - min) / (max - min);
+        let x_offset = (rect.width * ratio - rect.width / 2.0).round() as i32;
+        self.session
+            .action_chain()
+            .move_to_element_with_offset(self, x_offset, 0)
+            .click()
+            .perform()?;
+
+        // Strategy 2: Adjust the value one step at a time using the arrow keys.
+        let num_steps = ((target - current_value()?) / step).round() as i64;
+        if num_steps != 0 {
+            let key = || {
+                if num_steps > 0 {
+                    Keys::Right
+                } else {
+                    Keys::Left
+                }
+            };
+            let keys = (0..num_steps.abs()).fold(TypingData::from(""), |acc, _| acc + key());
+            self.send_keys(keys)?;
+        }
+
+        if (current_value()? - target).abs() < step / 2.0 {
+            Ok(())
+        } else {
+            Err(WebDriverError::InvalidElementState(WebDriverErrorInfo::new(&format!(
+                "Unable to set slider value to {}",
+                target
+            ))))
+        }
+    }
+
+    /// Set the HTML contents of a `contenteditable` element, and dispatch an `input` event so
+    /// that any rich-text editor listening for changes is notified.
+    ///
+    /// This is typically more reliable than `send_keys()` for rich-text editors, which often
+    /// handle individual keystrokes inconsistently.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let editor = driver.find_element(By::Css("div[contenteditable='true']"))?;
+    /// editor.set_content_editable_html("<p>Hello <b>world</b></p>")?;
+    /// ```
+    pub fn set_content_editable_html(&self, html: &str) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(html)?;
+        self.session.execute_script_with_args(
+            r#"
+            arguments[0].innerHTML = arguments[1];
+            arguments[0].dispatchEvent(new Event("input", { bubbles: true }));
+            "#,
+            &args,
+        )?;
+        Ok(())
+    }
+
+    /// Set the plain text contents of a `contenteditable` element, and dispatch an `input` event
+    /// so that any rich-text editor listening for changes is notified.
+    ///
+    /// Unlike `set_content_editable_html()`, the text is not parsed as HTML.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let editor = driver.find_element(By::Css("div[contenteditable='true']"))?;
+    /// editor.set_content_editable_text("Hello <world>")?;
+    /// ```
+    pub fn set_content_editable_text(&self, text: &str) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(text)?;
+        self.session.execute_script_with_args(
+            r#"
+            arguments[0].textContent = arguments[1];
+            arguments[0].dispatchEvent(new Event("input", { bubbles: true }));
+            "#,
+            &args,
+        )?;
+        Ok(())
+    }
+
+    /// Take a screenshot of this WebElement and return it as a base64-encoded
+    /// String.
+    pub fn screenshot_as_base64(&self) -> WebDriverResult<String> {
+        let v = self.cmd(Command::TakeElementScreenshot(self.element_id.clone()))?;
+        convert_json(&v["value"])
+    }
+
+    /// Take a screenshot of this WebElement and return it as PNG bytes.
+    pub fn screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
+        let s = self.screenshot_as_base64()?;
+        let bytes: Vec<u8> = decode(&s)?;
+        Ok(bytes)
+    }
+
+    /// Take a screenshot of this WebElement and write it to the specified
+    /// filename.
+    pub fn screenshot(&self, path: &Path) -> WebDriverResult<()> {
+        let png = self.screenshot_as_png()?;
+        let mut file = File::create(path)?;
+        file.write_all(&png)?;
         Ok(())
     }
 
+    /// Take a screenshot of this WebElement plus the surrounding area, and return it as
+    /// PNG bytes.
+    ///
+    /// The element is scrolled into the center of the viewport, and the viewport screenshot
+    /// is then cropped to the element bounds expanded by `padding` CSS pixels on each side.
+    /// The crop is clamped to the edges of the viewport, so the padding may be smaller
+    /// (or absent) on sides where the element is close to the edge of the page.
+    ///
+    /// Returns `WebDriverError::ElementNotInteractable` if no part of the element (including
+    /// the padding) is within the viewport, e.g. for a zero-size element with no padding.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let png = elem.screenshot_with_padding(20)?;
+    /// #     assert!(!png.is_empty());
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn screenshot_with_padding(&self, padding: u32) -> WebDriverResult<Vec<u8>> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self.session.execute_script_with_args(
+            r#"
+            arguments[0].scrollIntoView({ block: "center", inline: "center" });
+            let rect = arguments[0].getBoundingClientRect();
+            return [rect.left, rect.top, rect.right, rect.bottom, window.devicePixelRatio || 1];
+            "#,
+            &args,
+        )?;
+        let (left, top, right, bottom, ratio): (f64, f64, f64, f64, f64) = ret.convert()?;
+
+        let png = self.session.screenshot_as_png()?;
+        let image = image::load_from_memory_with_format(&png, ImageFormat::Png)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        // Convert from CSS pixels to screenshot pixels, clamped to the screenshot bounds.
+        let padding = f64::from(padding);
+        let scale = |value: f64, max: u32| ((value * ratio).round().max(0.0) as u32).min(max);
+        let x1 = scale(left - padding, image.width());
+        let y1 = scale(top - padding, image.height());
+        let x2 = scale(right + padding, image.width());
+        let y2 = scale(bottom + padding, image.height());
+        if x2 <= x1 || y2 <= y1 {
+            return Err(WebDriverError::ElementNotInteractable(WebDriverErrorInfo::new(
+                "Cannot take screenshot: the element is not within the viewport",
+            )));
+        }
+
+        let cropped = image.crop_imm(x1, y1, x2.saturating_sub(x1), y2.saturating_sub(y1));
+        let mut bytes = Vec::new();
+        cropped
+            .write_to(&mut bytes, ImageOutputFormat::Png)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(bytes)
+    }
+
     /// Focus this WebElement using JavaScript.
     ///
     /// # Example:
@@ -622,6 +1426,149 @@ impl<'a> WebElement<'a> {
         Ok(())
     }
 
+    /// Perform a two-finger pinch or spread gesture centered on this element.
+    ///
+    /// A `scale` greater than 1.0 spreads the fingers apart (zoom in), and a `scale` less
+    /// than 1.0 pinches them together (zoom out). The distance between the fingers changes by
+    /// the specified factor. Both fingers move at the same time, using two touch pointer
+    /// sources in a single action sequence.
+    ///
+    /// **NOTE:** This requires a browser that supports touch input, such as a mobile browser
+    /// or a desktop browser with touch emulation enabled (e.g. via
+    /// `WebDriver::set_device_metrics()` with `mobile` set to true, in Chromium).
+    ///
+    /// # Example:
+    /// ```ignore
+    /// let map = driver.find_element(By::Id("map"))?;
+    /// // Zoom in.
+    /// map.pinch(2.0)?;
+    /// ```
+    pub fn pinch(&self, scale: f64) -> WebDriverResult<()> {
+        if !(scale > 0.0 && scale.is_finite()) {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(&format!(
+                "Invalid pinch scale: {}",
+                scale
+            ))));
+        }
+
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self.session.execute_script_with_args(
+            r#"
+            arguments[0].scrollIntoView({ block: "center", inline: "center" });
+            let rect = arguments[0].getBoundingClientRect();
+            return [rect.left + rect.width / 2, rect.top + rect.height / 2, rect.width, rect.height];
+            "#,
+            &args,
+        )?;
+        let (x, y, width, height): (f64, f64, f64, f64) = ret.convert()?;
+
+        // Each finger starts this far from the center. Pinching starts further apart, so that
+        // the fingers do not end up on top of each other.
+        let base = (width.min(height) / 4.0).max(10.0);
+        let start = if scale < 1.0 {
+            base * 2.0
+        } else {
+            base
+        };
+        let end = start * scale;
+
+        const STEPS: u32 = 10;
+        let fingers_at = |offset: f64| {
+            [
+                (0, TouchAction::MoveTo((x - offset).round() as i32, y.round() as i32)),
+                (1, TouchAction::MoveTo((x + offset).round() as i32, y.round() as i32)),
+            ]
+        };
+        let mut actions = self
+            .session
+            .action_chain()
+            .touch_tick(&fingers_at(start))
+            .touch_tick(&[(0, TouchAction::Down), (1, TouchAction::Down)]);
+        for step in 1..=STEPS {
+            let offset = start + (end - start) * f64::from(step) / f64::from(STEPS);
+            actions = actions.touch_tick(&fingers_at(offset));
+        }
+        actions.touch_tick(&[(0, TouchAction::Up), (1, TouchAction::Up)]).perform()
+    }
+
+    /// Dispatch a synthetic DOM event of the specified type on this element using JavaScript.
+    ///
+    /// This is useful for frameworks that listen for specific events (e.g. `input`, `change`,
+    /// `blur` or `mouseover`) that are not necessarily produced by `click()` or `send_keys()`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.dispatch_event("click", true)?;
+    /// #     let elem_result = driver.find_element(By::Id("button-result"))?;
+    /// #     assert_eq!(elem_result.text()?, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn dispatch_event(&self, event_type: &str, bubbles: bool) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(event_type)?;
+        args.push(bubbles)?;
+        self.session.execute_script_with_args(
+            r#"
+            let event = new Event(arguments[1], { bubbles: arguments[2], cancelable: true });
+            arguments[0].dispatchEvent(event);
+            "#,
+            &args,
+        )?;
+        Ok(())
+    }
+
+    /// Dispatch a synthetic `CustomEvent` of the specified type on this element using JavaScript.
+    /// The `detail` value will be available to event listeners as `event.detail`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// elem.dispatch_custom_event("my-event", true, serde_json::json!({"key": "value"}))?;
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn dispatch_custom_event(
+        &self,
+        event_type: &str,
+        bubbles: bool,
+        detail: serde_json::Value,
+    ) -> WebDriverResult<()> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(event_type)?;
+        args.push(bubbles)?;
+        args.push(detail)?;
+        self.session.execute_script_with_args(
+            r#"
+            let event = new CustomEvent(
+                arguments[1],
+                { bubbles: arguments[2], cancelable: true, detail: arguments[3] }
+            );
+            arguments[0].dispatchEvent(event);
+            "#,
+            &args,
+        )?;
+        Ok(())
+    }
+
     /// Get the innerHtml property of this element.
     ///
     /// # Example:
@@ -663,6 +1610,223 @@ impl<'a> WebElement<'a> {
     pub fn outer_html(&self) -> WebDriverResult<String> {
         self.get_property("outerHTML").map(|x| x.unwrap_or_default())
     }
+
+    /// Get the innerText property of this element.
+    ///
+    /// There are three ways to get the text of an element, and they behave slightly differently:
+    /// - `text()` returns the text as rendered, as normalized by the WebDriver server.
+    /// - `inner_text()` returns the `innerText` property. This is also the rendered text,
+    ///   so hidden elements are excluded and line breaks are preserved, but it is computed by
+    ///   the browser itself and is not further normalized.
+    /// - `text_content()` returns the `textContent` property, which is the raw text of all child
+    ///   nodes, including any text within hidden elements.
+    ///
+    /// For example, given the following element:
+    ///
+    /// `<div id="example">Visible<span style="display: none"> hidden</span></div>`
+    ///
+    /// `inner_text()` would return `"Visible"` whereas `text_content()` would return
+    /// `"Visible hidden"`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("button1"))?.click()?;
+    /// let elem = driver.find_element(By::Id("button-result"))?;
+    /// let text = elem.inner_text()?;
+    /// #     assert_eq!(text, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn inner_text(&self) -> WebDriverResult<String> {
+        self.get_property("innerText").map(|x| x.unwrap_or_default())
+    }
+
+    /// Get the textContent property of this element. Unlike `text()` and `inner_text()`,
+    /// this includes the text of hidden child elements.
+    ///
+    /// See `inner_text()` for more details on the differences between these methods.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// #     driver.find_element(By::Id("button1"))?.click()?;
+    /// let elem = driver.find_element(By::Id("button-result"))?;
+    /// let text = elem.text_content()?;
+    /// #     assert_eq!(text, "Button 1 clicked");
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn text_content(&self) -> WebDriverResult<String> {
+        self.get_property("textContent").map(|x| x.unwrap_or_default())
+    }
+
+    /// Get a CSS selector that uniquely identifies this element, e.g.
+    /// `"#main > div:nth-child(2) > button:nth-child(1)"`.
+    ///
+    /// The selector is built by walking up from this element towards the root, using
+    /// `:nth-child()` to identify each element within its parent, and stopping early at the
+    /// first ancestor (or the element itself) with a unique `id`. This is useful for logging
+    /// which element an operation failed on, or for re-selecting the element later.
+    ///
+    /// **NOTE:** The selector depends on the structure of the page at the time it is generated,
+    /// so it may no longer match the same element after the DOM changes. For elements inside
+    /// a shadow DOM, the selector is relative to the shadow root.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Css("div[data-section='section-buttons'] button"))?;
+    /// let selector = elem.css_selector_path()?;
+    /// let elem2 = driver.find_element(By::Css(&selector))?;
+    /// assert!(elem.is_same_node(&elem2)?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn css_selector_path(&self) -> WebDriverResult<String> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self.session.execute_script_with_args(
+            r##"
+            let elem = arguments[0];
+            let root = elem.getRootNode();
+            let path = [];
+            while (elem && elem.nodeType === Node.ELEMENT_NODE) {
+                if (elem.id) {
+                    let idSelector = "#" + CSS.escape(elem.id);
+                    if (root.querySelectorAll(idSelector).length === 1) {
+                        path.unshift(idSelector);
+                        break;
+                    }
+                }
+                let selector = elem.localName;
+                let parent = elem.parentElement;
+                if (parent) {
+                    let index = Array.prototype.indexOf.call(parent.children, elem) + 1;
+                    selector += ":nth-child(" + index + ")";
+                }
+                path.unshift(selector);
+                elem = parent;
+            }
+            return path.join(" > ");
+            "##,
+            &args,
+        )?;
+        ret.convert()
+    }
+
+    /// Get an absolute XPath to this element, e.g. `"/html[1]/body[1]/div[2]/button[1]"`.
+    ///
+    /// Each step in the path identifies the element by its position among siblings with the
+    /// same name. Elements outside the XHTML namespace (such as SVG elements) are matched via
+    /// `local-name()`, since XPath would otherwise not match them in an HTML document.
+    ///
+    /// **NOTE:** XPath cannot cross shadow DOM boundaries, so for elements inside a shadow DOM
+    /// this returns `WebDriverError::InvalidArgument`. Use `css_selector_path()` instead.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let xpath = elem.xpath()?;
+    /// let elem2 = driver.find_element(By::XPath(&xpath))?;
+    /// assert!(elem.is_same_node(&elem2)?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn xpath(&self) -> WebDriverResult<String> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        let ret = self.session.execute_script_with_args(
+            r#"
+            let elem = arguments[0];
+            if (elem.getRootNode() !== document) {
+                return null;
+            }
+            let xhtml = "http://www.w3.org/1999/xhtml";
+            let steps = [];
+            for (; elem && elem.nodeType === Node.ELEMENT_NODE; elem = elem.parentNode) {
+                let index = 1;
+                for (let sib = elem.previousElementSibling; sib; sib = sib.previousElementSibling) {
+                    if (sib.localName === elem.localName && sib.namespaceURI === elem.namespaceURI) {
+                        index++;
+                    }
+                }
+                let name = elem.namespaceURI === xhtml
+                    ? elem.localName
+                    : "*[local-name()='" + elem.localName + "']";
+                steps.unshift(name + "[" + index + "]");
+            }
+            return "/" + steps.join("/");
+            "#,
+            &args,
+        )?;
+        match ret.value().as_str() {
+            Some(xpath) => Ok(xpath.to_string()),
+            None => Err(WebDriverError::InvalidArgument(WebDriverErrorInfo::new(
+                "Unable to get XPath for an element inside a shadow DOM. \
+                 Use css_selector_path() instead",
+            ))),
+        }
+    }
+
+    /// Return true if this element and the other element refer to the same DOM node,
+    /// otherwise false.
+    ///
+    /// Unlike `==`, which compares element ids, this compares the nodes themselves in the
+    /// browser. This is the only reliable way to tell whether elements returned by different
+    /// find operations are the same node.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use thirtyfour_sync::prelude::*;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     let caps = DesiredCapabilities::chrome();
+    /// #     let driver = WebDriver::new("http://localhost:4444/wd/hub", &caps)?;
+    /// #     driver.get("http://webappdemo")?;
+    /// let elem = driver.find_element(By::Id("button1"))?;
+    /// let elem2 = driver.find_element(By::Css("#button1"))?;
+    /// assert!(elem.is_same_node(&elem2)?);
+    /// #     let other = driver.find_element(By::Id("button-result"))?;
+    /// #     assert!(!elem.is_same_node(&other)?);
+    /// #     driver.quit()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_same_node(&self, other: &WebElement) -> WebDriverResult<bool> {
+        let mut args = ScriptArgs::new();
+        args.push(&self)?;
+        args.push(other)?;
+        let ret = self
+            .session
+            .execute_script_with_args(r#"return arguments[0] === arguments[1];"#, &args)?;
+        ret.convert()
+    }
 }
 
 impl<'a> fmt::Display for WebElement<'a> {
@@ -671,6 +1835,22 @@ impl<'a> fmt::Display for WebElement<'a> {
     }
 }
 
+impl<'a> PartialEq for WebElement<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.session.session_id() == other.session.session_id()
+            && self.element_id == other.element_id
+    }
+}
+
+impl<'a> Eq for WebElement<'a> {}
+
+impl<'a> Hash for WebElement<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.session.session_id().hash(state);
+        self.element_id.hash(state);
+    }
+}
+
 impl<'a> Serialize for WebElement<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where